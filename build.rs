@@ -0,0 +1,112 @@
+//! Build script of the template. With the `embed_inputs` feature, it collects the inputs and examples of the data
+//! folder into a table that is compiled into the binaries, see `read_file`.
+
+use std::{env, fs, path::Path};
+
+// The build script resolves the data folder with the modules of the template, so that it finds the same files as the
+// binaries that read them at runtime.
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/config.rs"]
+mod config;
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/day.rs"]
+mod day;
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/year.rs"]
+mod year;
+
+/// Stands in for the template module of the library, which the modules above refer to.
+mod template {
+    pub(crate) use super::config::{self, config};
+    pub(crate) use super::day::*;
+    pub(crate) use super::year::*;
+}
+
+use template::{config::data_dir, Year};
+
+/// Folders of a year whose files are embedded.
+const EMBEDDED_FOLDERS: [&str; 2] = ["inputs", "examples"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let data_dir = data_dir();
+
+    println!(
+        "cargo:rerun-if-env-changed={}",
+        template::config::DATA_DIR_ENV_VAR
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        template::config::config_path().display()
+    );
+    // picks up files that are added to the data folder.
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let mut entries = vec![];
+    let mut encrypted = vec![];
+
+    for (year, folder) in find_years(&data_dir)
+        .iter()
+        .flat_map(|year| EMBEDDED_FOLDERS.map(|folder| (year, folder)))
+    {
+        let Ok(files) = fs::read_dir(data_dir.join(year).join(folder)) else {
+            continue;
+        };
+
+        for path in files.flatten().map(|x| x.path()) {
+            let Some(name) = path.file_name().and_then(|x| x.to_str()) else {
+                continue;
+            };
+
+            if let Some(plain) = name.strip_suffix(".enc") {
+                if !path.with_file_name(plain).exists() {
+                    encrypted.push(format!("{year}/{folder}/{plain}"));
+                }
+            } else if name.ends_with(".txt") && fs::read_to_string(&path).is_ok() {
+                let key = format!("{year}/{folder}/{name}");
+                let path = fs::canonicalize(&path).unwrap_or(path);
+                println!("cargo:rerun-if-changed={}", path.display());
+                entries.push(format!(
+                    "    ({key:?}, include_str!({:?})),",
+                    path.display().to_string()
+                ));
+            }
+        }
+    }
+
+    // encrypted files are decrypted at runtime instead, which needs the key.
+    if !encrypted.is_empty() {
+        encrypted.sort_unstable();
+        println!(
+            "cargo:warning=Only encrypted versions exist of {}, they are read from the data folder at runtime. Run `cargo inputs decrypt` to embed them.",
+            encrypted.join(", ")
+        );
+    }
+
+    entries.sort_unstable();
+    let table = format!(
+        "/// Files of the data folder by their path in it, e.g. `2024/inputs/01.txt`.\npub static FILES: &[(&str, &str)] = &[\n{}\n];\n",
+        entries.join("\n")
+    );
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_data.rs");
+    fs::write(out, table).expect("could not write the embedded data");
+}
+
+/// Returns the folders of the data folder that are named like a year.
+fn find_years(data_dir: &Path) -> Vec<String> {
+    fs::read_dir(data_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name.parse::<Year>().is_ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
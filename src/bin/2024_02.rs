@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2024, 2);
 
 pub fn part_one(input: &str) -> Option<u32> {
     solve_part_one(input).ok()
@@ -26,7 +26,7 @@ fn solve_part_two(input: &str) -> anyhow::Result<u32> {
         .count() as u32)
 }
 
-fn analyze_report_differences(report: &Vec<i32>) -> Vec<i32> {
+fn analyze_report_differences(report: &[i32]) -> Vec<i32> {
     report
         .iter()
         .zip(report.iter().skip(1))
@@ -34,7 +34,7 @@ fn analyze_report_differences(report: &Vec<i32>) -> Vec<i32> {
         .collect_vec()
 }
 
-fn find_first_fault(analysis: &Vec<i32>) -> Option<usize> {
+fn find_first_fault(analysis: &[i32]) -> Option<usize> {
     let first_non_zero = analysis.iter().find(|item| **item != 0);
     match first_non_zero {
         Some(first_non_zero) => {
@@ -50,20 +50,19 @@ fn find_first_fault(analysis: &Vec<i32>) -> Option<usize> {
     }
 }
 
-fn is_report_safe_part1(report: &Vec<i32>) -> bool {
+fn is_report_safe_part1(report: &[i32]) -> bool {
     find_first_fault(&analyze_report_differences(report)).is_none()
 }
 
-fn is_report_safe_part2(report: &Vec<i32>) -> bool {
+fn is_report_safe_part2(report: &[i32]) -> bool {
     match find_first_fault(&analyze_report_differences(report)) {
         Some(fault_position) => (fault_position.saturating_sub(1)
             ..report.len().min(fault_position + 2)) // check positions on both sides of fault
-            .map(|report_position| {
-                let mut tolerated_report = report.clone();
-                let _ = tolerated_report.remove(report_position as usize);
+            .any(|report_position| {
+                let mut tolerated_report = report.to_vec();
+                let _ = tolerated_report.remove(report_position);
                 is_report_safe_part1(&tolerated_report)
-            })
-            .any(|safe| safe),
+            }),
         None => true,
     }
 }
@@ -86,7 +85,8 @@ mod tests {
 
     #[test]
     fn test_report_safe_part1() {
-        let reports = parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let reports =
+            parse_input(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
         let safe_result = is_report_safe_part1(&reports[0]);
         assert!(safe_result);
 
@@ -103,13 +103,13 @@ mod tests {
 
     #[test]
     fn test_find_fault() {
-        let result = find_first_fault(&vec![1, 2, 7, 8, 9]);
+        let result = find_first_fault(&[1, 2, 7, 8, 9]);
         assert_eq!(result, Some(2))
     }
 
     #[test]
     fn test_parse_input() {
-        let result = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let result = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
         assert!(result.is_ok());
         assert_eq!(
             result.unwrap(),
@@ -126,13 +126,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4));
     }
 }
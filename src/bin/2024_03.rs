@@ -1,15 +1,15 @@
 use nom::{bytes::complete::tag, combinator::value, IResult};
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     match multiple_mul_parser(input) {
         Ok((_, muls)) => Some(
             muls.iter()
                 .filter_map(|op| match op {
-                    Operation::DO => None,
-                    Operation::DONT => None,
-                    Operation::MUL(first, second) => Some(first * second),
+                    Operation::Do => None,
+                    Operation::Dont => None,
+                    Operation::Mul(first, second) => Some(first * second),
                 })
                 .sum::<i32>() as u32,
         ),
@@ -24,15 +24,15 @@ pub fn part_two(input: &str) -> Option<u32> {
             let count = ops
                 .iter()
                 .filter_map(|op| match op {
-                    Operation::DO => {
+                    Operation::Do => {
                         enabled = true;
                         None
                     }
-                    Operation::DONT => {
+                    Operation::Dont => {
                         enabled = false;
                         None
                     }
-                    Operation::MUL(first, second) => enabled.then_some(first * second),
+                    Operation::Mul(first, second) => enabled.then_some(first * second),
                 })
                 .sum::<i32>() as u32;
             Some(count)
@@ -43,9 +43,9 @@ pub fn part_two(input: &str) -> Option<u32> {
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum Operation {
-    DO,
-    DONT,
-    MUL(i32, i32),
+    Do,
+    Dont,
+    Mul(i32, i32),
 }
 
 fn multple_op_parser(mut input: &str) -> IResult<&str, Vec<Operation>> {
@@ -69,11 +69,11 @@ fn op_parser(input: &str) -> IResult<&str, Operation> {
 }
 
 fn do_parser(input: &str) -> IResult<&str, Operation> {
-    value(Operation::DO, tag("do()"))(input)
+    value(Operation::Do, tag("do()"))(input)
 }
 
 fn dont_parser(input: &str) -> IResult<&str, Operation> {
-    value(Operation::DONT, tag("don't()"))(input)
+    value(Operation::Dont, tag("don't()"))(input)
 }
 
 fn multiple_mul_parser(mut input: &str) -> IResult<&str, Vec<Operation>> {
@@ -98,7 +98,7 @@ fn mul_parser(input: &str) -> IResult<&str, Operation> {
         nom::character::complete::i32,
     )(input)?;
     let (input, _) = tag(")")(input)?;
-    Ok((input, Operation::MUL(first_arg, second_arg)))
+    Ok((input, Operation::Mul(first_arg, second_arg)))
 }
 
 #[cfg(test)]
@@ -108,33 +108,37 @@ mod tests {
     #[test]
     fn test_mul_parser() {
         let (_, result) = mul_parser("mul(123,231)").unwrap();
-        assert_eq!(result, Operation::MUL(123, 231));
+        assert_eq!(result, Operation::Mul(123, 231));
     }
 
     #[test]
     fn test_multiple_mul_parser() {
         let (_, result) =
-            multiple_mul_parser(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+            multiple_mul_parser(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
         assert_eq!(
             result,
             vec!(
-                Operation::MUL(2, 4),
-                Operation::MUL(5, 5),
-                Operation::MUL(11, 8),
-                Operation::MUL(8, 5)
+                Operation::Mul(2, 4),
+                Operation::Mul(5, 5),
+                Operation::Mul(11, 8),
+                Operation::Mul(8, 5)
             )
         );
     }
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(161));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples",
+            PUZZLE,
+            advent_of_code::template::Part::Two,
+        ));
         assert_eq!(result, Some(48));
     }
 }
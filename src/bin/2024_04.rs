@@ -7,7 +7,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2024, 4);
 
 pub fn part_one(input: &str) -> Option<u32> {
     match Puzzle::try_from(input) {
@@ -67,7 +67,7 @@ impl Puzzle {
 
         let start_locations = (0..rows).cartesian_product(0..columns);
 
-        let target_word = input.chars().map(|c| Some(c));
+        let target_word = input.chars().map(Some);
 
         let possible_locations = start_locations
             .clone()
@@ -102,9 +102,7 @@ impl Puzzle {
                 .then_some(possible)
         });
 
-        let results = tested_locations.collect_vec();
-
-        results
+        tested_locations.collect_vec()
     }
 }
 
@@ -142,13 +140,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(9));
     }
 
@@ -12,7 +12,7 @@ use nom::{
     IResult,
 };
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2024, 5);
 
 pub fn part_one(input: &str) -> Option<u32> {
     match Puzzle::try_from(input) {
@@ -39,9 +39,9 @@ pub fn part_two(input: &str) -> Option<u32> {
             Some(
                 invalid_updates
                     .into_iter()
-                    .filter_map(|update| {
+                    .map(|update| {
                         let reordered_update = puzzle.ensure_update_ordering(update);
-                        Some(reordered_update.get(update.len() / 2).unwrap().clone())
+                        reordered_update[update.len() / 2]
                     })
                     .sum::<u32>(),
             )
@@ -69,8 +69,8 @@ enum UpdateState {
 }
 
 impl Puzzle {
-    fn check_update(&self, update: &Vec<u32>) -> UpdateState {
-        let updated_pages = update.clone().into_iter().collect::<HashSet<u32>>();
+    fn check_update(&self, update: &[u32]) -> UpdateState {
+        let updated_pages = update.iter().copied().collect::<HashSet<u32>>();
         let mut restricted_rules = self.rules.rule_list.clone();
         restricted_rules.retain(|k, _| updated_pages.contains(k));
         restricted_rules.iter_mut().for_each(|(_, hs)| {
@@ -79,43 +79,39 @@ impl Puzzle {
 
         let mut seen_set: HashSet<u32> = HashSet::new();
         for update_item in update {
-            if let Some(applicable_rules) = restricted_rules.get(&update_item) {
+            if let Some(applicable_rules) = restricted_rules.get(update_item) {
                 if seen_set.iter().any(|seen| applicable_rules.contains(seen)) {
                     return UpdateState::Invalid;
                 }
             }
             seen_set.insert(*update_item);
         }
-        return UpdateState::Valid;
+        UpdateState::Valid
     }
 
     fn get_valid_updates(&self) -> Vec<&Vec<u32>> {
         self.updates
             .iter()
-            .filter_map(|update| {
-                (self.check_update(update) == UpdateState::Valid).then_some(update)
-            })
+            .filter(|update| self.check_update(update) == UpdateState::Valid)
             .collect_vec()
     }
 
     fn get_invalid_updates(&self) -> Vec<&Vec<u32>> {
         self.updates
             .iter()
-            .filter_map(|update| {
-                (self.check_update(update) == UpdateState::Invalid).then_some(update)
-            })
+            .filter(|update| self.check_update(update) == UpdateState::Invalid)
             .collect_vec()
     }
 
-    fn ensure_update_ordering(&self, update: &Vec<u32>) -> Vec<u32> {
-        let updated_pages = update.clone().into_iter().collect::<HashSet<u32>>();
+    fn ensure_update_ordering(&self, update: &[u32]) -> Vec<u32> {
+        let updated_pages = update.iter().copied().collect::<HashSet<u32>>();
         let mut restricted_rules = self.rules.rule_list.clone();
         restricted_rules.retain(|k, _| updated_pages.contains(k));
         restricted_rules.iter_mut().for_each(|(_, hs)| {
             hs.retain(|after| updated_pages.contains(after));
         });
 
-        let mut update = update.clone();
+        let mut update = update.to_vec();
         update.sort_by(|a, b| {
             if let Some(a_rules) = restricted_rules.get(a) {
                 if a_rules.contains(b) {
@@ -190,13 +186,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(123));
     }
 }
@@ -1,81 +1,206 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, archive_year, download, examples, inputs, leaderboard, read, report, scaffold, session,
+    solve, status, time,
+};
+use advent_of_code::template::config::{load_config, CONFIG_FILE_NAME};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::aoc_client::{is_valid_profile, PROFILE_ENV_VAR};
+    use advent_of_code::template::commands::inputs;
+    use advent_of_code::template::{Day, MergePolicy, Part, PuzzleId, ReportFormat, Year};
+    use std::{env, path::PathBuf, process};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+            puzzle_only: bool,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
+        },
+        Leaderboard {
+            year: Year,
+            id: String,
+            readme: bool,
+        },
+        SessionCheck,
+        Inputs {
+            action: inputs::Action,
+            year: Option<Year>,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            submit: Option<Part>,
+            force: bool,
+            wait: bool,
         },
         All {
+            year: Year,
             release: bool,
         },
+        ArchiveYear {
+            year: Year,
+            next: Year,
+        },
         Time {
             all: bool,
+            year: Year,
             day: Option<Day>,
             store: bool,
+            table: Option<String>,
+            policy: MergePolicy,
+            trend: usize,
+        },
+        Report {
+            year: Year,
+            format: ReportFormat,
+            out: Option<PathBuf>,
+            table: Option<String>,
+        },
+        Status {
+            year: Year,
+            tests: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // commands read the profile from the environment, so that it is passed on to child processes.
+        if let Some(profile) = args.opt_value_from_str::<_, String>("--profile")? {
+            if !is_valid_profile(&profile) {
+                eprintln!(
+                    "Invalid profile name \"{profile}\", use letters, digits, `-` and `_` only."
+                );
+                process::exit(1);
+            }
+            env::set_var(PROFILE_ENV_VAR, profile);
+        }
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(args.opt_value_from_str("--year")?)?,
                 release: args.contains("--release"),
             },
+            Some("archive-year") => {
+                let year = parse_year(args.opt_value_from_str("--year")?)?;
+                let next = match args.opt_value_from_str("--next")? {
+                    Some(next) => next,
+                    None => Year::new(year.into_inner() + 1).ok_or("invalid year")?,
+                };
+                AppArguments::ArchiveYear { year, next }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let table = args.opt_value_from_str("--table")?;
+                let policy = args.opt_value_from_str("--policy")?.unwrap_or_default();
+                let trend = args.opt_value_from_str("--trend")?.unwrap_or(10);
+                let year = args.opt_value_from_str("--year")?;
+                // `--year` applies to a day on its own, e.g. `cargo time 5 --year 2023`.
+                let puzzle = match args.opt_free_from_str::<String>()? {
+                    Some(s) => Some(PuzzleId::parse_with_year(&s, year)?),
+                    None => None,
+                };
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    year: parse_year(puzzle.map(|x| x.year).or(year))?,
+                    day: puzzle.map(|x| x.day),
                     store,
+                    table,
+                    policy,
+                    trend,
                 }
             }
+            Some("report") => AppArguments::Report {
+                year: parse_year(args.opt_value_from_str("--year")?)?,
+                format: args
+                    .opt_value_from_str("--format")?
+                    .unwrap_or(ReportFormat::Markdown),
+                out: args.opt_value_from_str("--out")?,
+                table: args.opt_value_from_str("--table")?,
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
+                puzzle_only: args.contains("--puzzle-only"),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: args.free_from_str()?,
+            },
+            Some("leaderboard") => {
+                let readme = args.contains("--readme");
+                AppArguments::Leaderboard {
+                    year: parse_year(args.opt_value_from_str("--year")?)?,
+                    id: args.free_from_str()?,
+                    readme,
+                }
+            }
+            Some("inputs") => {
+                let action = match args.free_from_str::<String>()?.as_str() {
+                    "encrypt" => inputs::Action::Encrypt,
+                    "decrypt" => inputs::Action::Decrypt,
+                    x => {
+                        eprintln!("Unknown inputs command: {x}");
+                        process::exit(1);
+                    }
+                };
+                AppArguments::Inputs {
+                    action,
+                    year: args.opt_value_from_str("--year")?,
+                }
+            }
+            Some("session") => match args.free_from_str::<String>()?.as_str() {
+                "check" => AppArguments::SessionCheck,
+                x => {
+                    eprintln!("Unknown session command: {x}");
+                    process::exit(1);
+                }
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
+                wait: args.contains("--wait"),
                 dhat: args.contains("--dhat"),
             },
+            Some("status") => AppArguments::Status {
+                year: parse_year(args.opt_value_from_str("--year")?)?,
+                tests: args.contains("--tests"),
+            },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,52 +218,79 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Commands that work on a whole year default to the configured year.
+    fn parse_year(year: Option<Year>) -> Result<Year, &'static str> {
+        year.or_else(Year::configured)
+            .ok_or("no year given. Pass `--year` or set `year` in `aoc.toml`.")
+    }
 }
 
 fn main() {
+    // reads `aoc.toml` up front, so that an invalid config fails before any command runs.
+    if let Err(e) = load_config() {
+        eprintln!("Invalid {CONFIG_FILE_NAME}: {e}");
+        std::process::exit(1);
+    }
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All { year, release } => all::handle(year, release),
+            AppArguments::ArchiveYear { year, next } => archive_year::handle(year, next),
+            AppArguments::Time {
+                year,
                 day,
+                all,
+                store,
+                table,
+                policy,
+                trend,
+            } => time::handle(year, day, all, store, table.as_deref(), policy, trend),
+            AppArguments::Report {
+                year,
+                format,
+                out,
+                table,
+            } => {
+                report::handle(year, format, out, table.as_deref());
+            }
+            AppArguments::Download {
+                puzzle,
+                puzzle_only,
+            } => download::handle(puzzle, puzzle_only),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::Leaderboard { year, id, readme } => {
+                leaderboard::handle(year, &id, readme);
+            }
+            AppArguments::SessionCheck => session::check(),
+            AppArguments::Inputs { action, year } => inputs::handle(action, year),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle, false);
+                    examples::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                force,
+                wait,
+            } => solve::handle(puzzle, release, dhat, submit, force, wait),
+            AppArguments::Status { year, tests } => status::handle(year, tests),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,980 @@
+/// Built-in client for the Advent of Code website.
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use sha2::{Digest, Sha256};
+use tinyjson::JsonValue;
+
+use crate::template::config::project_root;
+use crate::template::encryption;
+use crate::template::{config, get_data_path, Part, PuzzleId, Year};
+
+/// Overrides the base url of the Advent of Code website, e.g. to point the client at a local stub server.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// Holds the session cookie. Takes precedence over the session file in the home directory.
+pub const SESSION_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+
+/// Selects a named session profile, e.g. to switch between a work and a personal account.
+pub const PROFILE_ENV_VAR: &str = "AOC_PROFILE";
+
+/// The heading of the second part in puzzle descriptions, once it is unlocked.
+pub const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+static CACHE_DIR: &str = "target/aoc-cache";
+
+/// Pages served to logged out visitors, e.g. after the session cookie expired, contain one of these.
+static LOGGED_OUT_MARKERS: [&str; 3] = ["/auth/login", "Please log in", "please identify yourself"];
+
+static LEADERBOARD_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// The minimum time between two requests, shared by all commands through a stamp file in the cache.
+static MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum AocCommandError {
+    /// No session cookie was found in the environment or in the session file of a profile.
+    MissingSession(Option<String>),
+    /// The website rejected the session cookie or responded with a page for logged out visitors.
+    SessionExpired,
+    /// The website responded with an unexpected status code.
+    HttpStatus(u16),
+    /// The request could not be sent or its response could not be received.
+    Transport(String),
+    /// The response did not have the expected shape.
+    Parse(String),
+    IO(io::Error),
+}
+
+impl Error for AocCommandError {}
+
+impl Display for AocCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocCommandError::MissingSession(profile) => write!(
+                f,
+                "no session cookie found. Set {} or create \"~/{}\".",
+                get_session_env_var(profile.as_deref()),
+                get_session_file_name(profile.as_deref())
+            ),
+            AocCommandError::SessionExpired => write!(
+                f,
+                "the session cookie has expired or is invalid. Log in to the website again, update the cookie and verify it with `cargo session check`."
+            ),
+            AocCommandError::HttpStatus(404) => write!(
+                f,
+                "the website responded with status 404. The puzzle might not be unlocked yet."
+            ),
+            AocCommandError::HttpStatus(status) => {
+                write!(f, "the website responded with status {status}.")
+            }
+            AocCommandError::Transport(e) => write!(f, "the request failed: {e}"),
+            AocCommandError::Parse(e) => write!(f, "could not parse the response: {e}"),
+            AocCommandError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocCommandError {
+    fn from(e: io::Error) -> Self {
+        AocCommandError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: Option<PathBuf>,
+    min_request_interval: Duration,
+}
+
+impl AocClient {
+    /// Creates a client without a cache or rate limit.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        AocClient {
+            agent: ureq::AgentBuilder::new()
+                .timeout(config().client.timeout)
+                .user_agent(&user_agent())
+                .build(),
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            cache_dir: None,
+            min_request_interval: MIN_REQUEST_INTERVAL,
+        }
+    }
+
+    /// Caches responses that do not change anymore in `dir` and rate limits requests.
+    #[must_use]
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Overrides the minimum time between two requests of a cached client.
+    #[must_use]
+    pub fn with_min_request_interval(mut self, interval: Duration) -> Self {
+        self.min_request_interval = interval;
+        self
+    }
+
+    /// Configures a client from `AOC_BASE_URL` and the session cookie of the selected profile.
+    /// Caches to `target/aoc-cache` in the root of the project, in a subfolder per session as inputs differ by account.
+    pub fn from_env() -> Result<Self, AocCommandError> {
+        let profile = get_profile();
+        let session = get_session(profile.as_deref())
+            .ok_or_else(|| AocCommandError::MissingSession(profile.clone()))?;
+        let base_url = env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let cache_dir = project_root()
+            .join(CACHE_DIR)
+            .join(get_session_key(&session));
+
+        Ok(AocClient::new(&base_url, &session).with_cache(cache_dir))
+    }
+
+    /// Fetches the personal puzzle input. Inputs never change, so they are always cached.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocCommandError> {
+        self.get_cached(
+            &format!("/{}/day/{}/input", puzzle.year, puzzle.day.into_inner()),
+            &format!("{}/{}-input.txt", puzzle.year, puzzle.day),
+            |_, _| true,
+        )
+    }
+
+    /// Fetches the puzzle description as markdown.
+    /// Contains the second part once the first part was solved.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocCommandError> {
+        // the description only changes until the second part is unlocked.
+        let html = self.get_cached(
+            &format!("/{}/day/{}", puzzle.year, puzzle.day.into_inner()),
+            &format!("{}/{}-puzzle.html", puzzle.year, puzzle.day),
+            |html, _| find_articles(html).len() > 1,
+        )?;
+
+        let articles = find_articles(&html);
+        if articles.is_empty() {
+            return Err(AocCommandError::Parse(
+                "expected puzzle page to contain a description.".into(),
+            ));
+        }
+
+        Ok(articles
+            .into_iter()
+            .map(html_to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Submits an answer and returns the message the website responded with.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, AocCommandError> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        );
+
+        let part = part.to_string();
+        self.wait_for_rate_limit();
+        let html = read_response(
+            self.agent
+                .post(&url)
+                .set("Cookie", &format!("session={}", self.session))
+                .send_form(&[("level", &part), ("answer", answer)]),
+        )?;
+
+        find_articles(&html)
+            .first()
+            .map(|article| strip_tags(article))
+            .ok_or(AocCommandError::Parse(
+                "expected submission response to contain a message.".into(),
+            ))
+    }
+
+    /// Fetches a private leaderboard of a year.
+    /// The website asks to not fetch it more often than every 15 minutes, so it is cached for as long.
+    pub fn get_leaderboard(&self, year: Year, id: &str) -> Result<JsonValue, AocCommandError> {
+        let json = self.get_cached(
+            &format!("/{year}/leaderboard/private/view/{id}.json"),
+            &format!("{year}/leaderboard-{id}.json"),
+            |_, age| age < LEADERBOARD_MAX_AGE,
+        )?;
+
+        JsonValue::from_str(&json).map_err(|e| AocCommandError::Parse(e.to_string()))
+    }
+
+    /// Returns the name of the user the session cookie belongs to, as shown in the header of every page.
+    pub fn get_user(&self) -> Result<String, AocCommandError> {
+        let html = self.get("/")?;
+        find_user(&html).ok_or(AocCommandError::Parse(
+            "expected page header to contain the user name.".into(),
+        ))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocCommandError> {
+        self.wait_for_rate_limit();
+        read_response(
+            self.agent
+                .get(&format!("{}{path}", self.base_url))
+                .set("Cookie", &format!("session={}", self.session))
+                .call(),
+        )
+    }
+
+    /// Serves a response from the cache if `is_fresh` holds for its contents and age, otherwise fetches it.
+    fn get_cached(
+        &self,
+        path: &str,
+        cache_file: &str,
+        is_fresh: impl Fn(&str, Duration) -> bool,
+    ) -> Result<String, AocCommandError> {
+        let cache_path = self.cache_dir.as_ref().map(|dir| dir.join(cache_file));
+
+        if let Some(cache_path) = &cache_path {
+            let age = fs::metadata(cache_path)
+                .and_then(|x| x.modified())
+                .ok()
+                .and_then(|x| SystemTime::now().duration_since(x).ok());
+
+            if let (Ok(cached), Some(age)) = (fs::read_to_string(cache_path), age) {
+                if is_fresh(&cached, age) {
+                    return Ok(cached);
+                }
+            }
+        }
+
+        let body = self.get(path)?;
+
+        if let Some(cache_path) = cache_path {
+            // the cache is an optimization, failing to write it is not an error.
+            let _ = write_file(&cache_path, &body);
+        }
+
+        Ok(body)
+    }
+
+    /// Waits until the minimum interval since the last request of any command passed.
+    fn wait_for_rate_limit(&self) {
+        let Some(dir) = &self.cache_dir else {
+            return;
+        };
+
+        let stamp = dir.join("last-request");
+
+        let elapsed = fs::metadata(&stamp)
+            .and_then(|x| x.modified())
+            .ok()
+            .and_then(|x| SystemTime::now().duration_since(x).ok());
+
+        if let Some(elapsed) = elapsed {
+            if elapsed < self.min_request_interval {
+                thread::sleep(self.min_request_interval - elapsed);
+            }
+        }
+
+        let _ = write_file(&stamp, "");
+    }
+}
+
+fn read_response(result: Result<ureq::Response, ureq::Error>) -> Result<String, AocCommandError> {
+    match result {
+        Ok(response) => {
+            let body = response.into_string()?;
+            // the website does not fail on expired cookies, but serves the pages to visitors instead.
+            if LOGGED_OUT_MARKERS.iter().any(|x| body.contains(x)) {
+                Err(AocCommandError::SessionExpired)
+            } else {
+                Ok(body)
+            }
+        }
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(AocCommandError::SessionExpired),
+        Err(ureq::Error::Status(status, _)) => Err(AocCommandError::HttpStatus(status)),
+        Err(ureq::Error::Transport(e)) => Err(AocCommandError::Transport(e.to_string())),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Downloads input and description of a puzzle to the data directory of its year.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocCommandError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = client.get_input(puzzle)?;
+    let description = client.get_puzzle(puzzle)?;
+
+    // inputs never change, but might have been edited by hand.
+    if encryption::read_to_string(&input_path).is_ok_and(|x| x == input) {
+        println!("🎄 Input \"{}\" is up to date.", &input_path);
+    } else {
+        write_file(&input_path, &input)?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    write_puzzle(&puzzle_path, &description)?;
+    Ok(())
+}
+
+/// Fetches the description of a puzzle, stores it and prints it to the terminal.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocCommandError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+    write_file(get_puzzle_path(puzzle), &format!("{description}\n"))?;
+    println!("{description}");
+    Ok(())
+}
+
+/// Refreshes the description of a puzzle without touching its input,
+/// e.g. to pick up the second part after solving the first.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<(), AocCommandError> {
+    let description = AocClient::from_env()?.get_puzzle(puzzle)?;
+    write_puzzle(&get_puzzle_path(puzzle), &description)?;
+    Ok(())
+}
+
+/// Writes a puzzle description and prints its second part if it was unlocked since the last download.
+fn write_puzzle(path: &str, puzzle: &str) -> Result<(), io::Error> {
+    let previous = encryption::read_to_string(path).unwrap_or_default();
+
+    if previous.trim_end() == puzzle {
+        println!("🎄 Puzzle \"{path}\" is up to date.");
+        return Ok(());
+    }
+
+    write_file(path, &format!("{puzzle}\n"))?;
+    println!("🎄 Successfully wrote puzzle to \"{path}\".");
+
+    if let Some(part_two) = find_new_part_two(&previous, puzzle) {
+        println!("🎄 Part two is unlocked!\n\n{part_two}");
+    }
+
+    Ok(())
+}
+
+/// Returns the second part of a puzzle description, if present.
+fn find_part_two(puzzle: &str) -> Option<&str> {
+    puzzle.find(PART_TWO_HEADING).map(|i| &puzzle[i..])
+}
+
+/// Returns the second part of `current` if the `previous` description had the first part only.
+fn find_new_part_two<'a>(previous: &str, current: &'a str) -> Option<&'a str> {
+    if previous.trim().is_empty() || find_part_two(previous).is_some() {
+        return None;
+    }
+    find_part_two(current)
+}
+
+fn write_file(path: impl AsRef<Path>, contents: &str) -> Result<(), io::Error> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+fn get_input_path(puzzle: PuzzleId) -> String {
+    get_data_path(puzzle.year, &format!("inputs/{}.txt", puzzle.day))
+}
+
+fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    get_data_path(puzzle.year, &format!("puzzles/{}.md", puzzle.day))
+}
+
+/// Identifies the template and the owner of the project to the website, as its maintainers ask automated tools to do.
+/// The owner is `contact` in `aoc.toml`, or the repository of `Cargo.toml`.
+fn user_agent() -> String {
+    let contact = config()
+        .client
+        .contact
+        .as_deref()
+        .or(option_env!("CARGO_PKG_REPOSITORY").filter(|x| !x.is_empty()));
+
+    match contact {
+        Some(contact) => format!("advent_of_code/{} ({contact})", env!("CARGO_PKG_VERSION")),
+        None => format!("advent_of_code/{}", env!("CARGO_PKG_VERSION")),
+    }
+}
+
+/// Names the cache of a session without storing the session cookie itself.
+fn get_session_key(session: &str) -> String {
+    Sha256::digest(session.as_bytes())[..8]
+        .iter()
+        .map(|x| format!("{x:02x}"))
+        .collect()
+}
+
+/// Returns the session profile selected with `AOC_PROFILE`, if any.
+pub fn get_profile() -> Option<String> {
+    env::var(PROFILE_ENV_VAR).ok().filter(|x| !x.is_empty())
+}
+
+/// Profile names end up in file names and environment variables.
+pub fn is_valid_profile(profile: &str) -> bool {
+    !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// E.g. `ADVENT_OF_CODE_SESSION_WORK` for the profile `work`.
+fn get_session_env_var(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!(
+            "{SESSION_ENV_VAR}_{}",
+            profile.to_ascii_uppercase().replace('-', "_")
+        ),
+        None => SESSION_ENV_VAR.into(),
+    }
+}
+
+/// E.g. `.adventofcode.work.session` for the profile `work`.
+fn get_session_file_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => SESSION_FILE_NAME.replace(".session", &format!(".{profile}.session")),
+        None => SESSION_FILE_NAME.into(),
+    }
+}
+
+/// Reads the session cookie of a profile from its environment variable or its file in the home directory.
+fn get_session(profile: Option<&str>) -> Option<String> {
+    let session = env::var(get_session_env_var(profile)).ok().or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(Path::new(&home).join(get_session_file_name(profile))).ok()
+    })?;
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the user name in the header of a page, without the star count that follows it.
+fn find_user(html: &str) -> Option<String> {
+    let tag = "<div class=\"user\">";
+    let start = html.find(tag)? + tag.len();
+    let end = start + html[start..].find("</div>")?;
+    let name = html[start..end].split("<span").next()?.trim();
+    (!name.is_empty()).then(|| decode_entities(name))
+}
+
+/// Returns the inner html of all `<article>` elements.
+fn find_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|x| start + x + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+/// Converts the subset of html used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<Option<String>> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let Some(tag_end) = rest[tag_start..].find('>').map(|x| tag_start + x) else {
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("\n\n## "),
+            ("p" | "h2" | "ul", _) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("\n- "),
+            ("br", _) => out.push('\n'),
+            ("pre", false) => {
+                out.push_str("\n\n```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("a", false) => {
+                let href = get_attribute(tag, "href");
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tidy_markdown(&out)
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        out.push_str(&text);
+        return;
+    }
+
+    let mut last_was_space = out.ends_with(char::is_whitespace);
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            out.push(c);
+            last_was_space = false;
+        }
+    }
+}
+
+/// Trims whitespace around lines outside of code blocks and collapses consecutive blank lines.
+fn tidy_markdown(markdown: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    let mut in_fence = false;
+
+    for line in markdown.lines() {
+        let line = if in_fence { line } else { line.trim() };
+
+        if line == "```" {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence && line.is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+
+        lines.push(line);
+    }
+
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+/// Removes all tags and collapses whitespace, e.g. for the message of a submission response.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        text.push_str(&rest[..tag_start]);
+        text.push(' ');
+        rest = match rest[tag_start..].find('>') {
+            Some(tag_end) => &rest[tag_start + tag_end + 1..],
+            None => "",
+        };
+    }
+
+    text.push_str(rest);
+
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" .", ".")
+        .replace(" ,", ",")
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+pub(crate) mod stub {
+    use super::AocClient;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    /// Serves a single canned response and reports the raw request it received.
+    pub fn serve(status: &str, body: &str) -> (AocClient, mpsc::Receiver<String>) {
+        serve_all(&[(status, body)])
+    }
+
+    /// Serves canned responses in order, one per request, and reports the raw requests it received. The client does not
+    /// wait between requests.
+    pub fn serve_all(responses: &[(&str, &str)]) -> (AocClient, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<String> = responses
+            .iter()
+            .map(|(status, body)| {
+                format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            })
+            .collect();
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                // the client might not wait for every request to be reported.
+                let _ = tx.send(handle(stream, &response));
+            }
+        });
+
+        let client = AocClient::new(&base_url, "abc").with_min_request_interval(Duration::ZERO);
+        (client, rx)
+    }
+
+    fn handle(stream: TcpStream, response: &str) -> String {
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some((key, value)) = line.split_once(':') {
+                if key.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            request.push_str(&line);
+            if line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        request.push_str(&String::from_utf8(body).unwrap());
+
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        request
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::stub::{serve, serve_all};
+    use super::{
+        find_new_part_two, get_session_key, html_to_markdown, strip_tags, user_agent,
+        AocCommandError,
+    };
+    use crate::template::{Part, PuzzleId};
+    use crate::{day, year};
+    use std::{
+        env, fs,
+        path::PathBuf,
+        process,
+        time::{Duration, Instant},
+    };
+
+    const PUZZLE: PuzzleId = PuzzleId {
+        year: year!(2024),
+        day: day!(1),
+    };
+
+    #[test]
+    fn fetches_inputs() {
+        let (client, request) = serve("200 OK", "1\n2\n");
+        assert_eq!(client.get_input(PUZZLE).unwrap(), "1\n2\n");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzles() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>Find the <em>sum</em>.</p></article>\
+            <p>Your puzzle answer was <code>11</code>.</p></main>";
+        let (client, _) = serve("200 OK", html);
+        assert_eq!(
+            client.get_puzzle(PUZZLE).unwrap(),
+            "## --- Day 1 ---\n\nFind the *sum*."
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let html = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer. <a href=\"/2024\">[Return]</a></p></article></main>";
+        let (client, request) = serve("200 OK", html);
+        assert_eq!(
+            client
+                .submit(PuzzleId::new(year!(2024), day!(5)), Part::Two, "42")
+                .unwrap(),
+            "That's the right answer! You are one gold star closer. [Return]"
+        );
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/5/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn maps_http_errors() {
+        let (client, _) = serve("400 Bad Request", "Please log in.");
+        assert!(matches!(
+            client.get_input(PUZZLE),
+            Err(AocCommandError::SessionExpired)
+        ));
+
+        let (client, _) = serve("404 Not Found", "");
+        assert!(matches!(
+            client.get_input(PUZZLE),
+            Err(AocCommandError::HttpStatus(404))
+        ));
+
+        let (client, _) = serve("200 OK", "<html></html>");
+        assert!(matches!(
+            client.get_leaderboard(year!(2024), "1"),
+            Err(AocCommandError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let html = "<h2>--- Day 2: Red-Nosed Reports ---</h2>\n<p>The data \
+            <a href=\"/2024/day/2/input\" target=\"_blank\">consists</a> of <em>reports</em>:</p>\n\
+            <pre><code>7 6 4\n1 &lt; 2\n</code></pre>\n<ul>\n<li><code>7 6 4</code>: <em>Safe</em></li>\n\
+            <li>Unsafe &amp; odd</li>\n</ul>";
+
+        let expected = [
+            "## --- Day 2: Red-Nosed Reports ---",
+            "",
+            "The data [consists](/2024/day/2/input) of *reports*:",
+            "",
+            "```",
+            "7 6 4",
+            "1 < 2",
+            "```",
+            "",
+            "- `7 6 4`: *Safe*",
+            "- Unsafe & odd",
+        ]
+        .join("\n");
+
+        assert_eq!(html_to_markdown(html), expected);
+    }
+
+    fn get_cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn keys_caches_by_session() {
+        let key = get_session_key("abc");
+        assert_eq!(key.len(), 16);
+        assert!(!key.contains("abc"));
+        assert_eq!(get_session_key("abc"), key);
+        assert_ne!(get_session_key("abd"), key);
+    }
+
+    #[test]
+    fn caches_inputs() {
+        let dir = get_cache_dir("inputs");
+        let (client, requests) = serve("200 OK", "1\n2\n");
+        let client = client.with_cache(&dir);
+
+        assert_eq!(client.get_input(PUZZLE).unwrap(), "1\n2\n");
+        assert_eq!(client.get_input(PUZZLE).unwrap(), "1\n2\n");
+        assert_eq!(requests.try_iter().count(), 1);
+        assert!(dir.join("2024").join("01-input.txt").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn caches_puzzles_once_part_two_is_unlocked() {
+        let dir = get_cache_dir("puzzles");
+        let part_one = "<article><p>One</p></article>";
+        let both = "<article><p>One</p></article><article><p>Two</p></article>";
+        let (client, requests) =
+            serve_all(&[("200 OK", part_one), ("200 OK", both), ("200 OK", "")]);
+        let client = client.with_cache(&dir);
+
+        assert_eq!(client.get_puzzle(PUZZLE).unwrap(), "One");
+        assert_eq!(client.get_puzzle(PUZZLE).unwrap(), "One\n\nTwo");
+        assert_eq!(client.get_puzzle(PUZZLE).unwrap(), "One\n\nTwo");
+
+        let requests: Vec<String> = requests.try_iter().collect();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains(&user_agent()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let dir = get_cache_dir("rate_limit");
+        let interval = Duration::from_millis(100);
+        let (client, requests) =
+            serve_all(&[("200 OK", "1\n"), ("200 OK", "<article>One</article>")]);
+        let client = client.with_cache(&dir).with_min_request_interval(interval);
+
+        let started = Instant::now();
+        client.get_input(PUZZLE).unwrap();
+        client.get_puzzle(PUZZLE).unwrap();
+        // file times lag behind the system clock by up to a timer tick.
+        assert!(started.elapsed() >= interval - Duration::from_millis(20));
+        assert_eq!(requests.try_iter().count(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn finds_new_part_two() {
+        let part_one = "## --- Day 1 ---\n\nOne";
+        let both = "## --- Day 1 ---\n\nOne\n\n## --- Part Two ---\n\nTwo";
+
+        assert_eq!(
+            find_new_part_two(part_one, both),
+            Some("## --- Part Two ---\n\nTwo")
+        );
+        assert_eq!(find_new_part_two(both, both), None);
+        assert_eq!(find_new_part_two("", both), None);
+        assert_eq!(find_new_part_two(part_one, part_one), None);
+    }
+
+    #[test]
+    fn detects_expired_sessions() {
+        let html = "<header><a href=\"/2024/auth/login\">[Log In]</a></header>\
+            <main><article><p>--- Day 1 ---</p></article></main>";
+        let (client, _) = serve("200 OK", html);
+        assert!(matches!(
+            client.get_puzzle(PUZZLE),
+            Err(AocCommandError::SessionExpired)
+        ));
+
+        let html = "<main><article><p>To play, please identify yourself via one of these services:</p></article></main>";
+        let (client, _) = serve("200 OK", html);
+        assert!(matches!(
+            client.submit(PUZZLE, Part::One, "42"),
+            Err(AocCommandError::SessionExpired)
+        ));
+    }
+
+    #[test]
+    fn fetches_users() {
+        let html = "<header><div class=\"user\">Alice &amp; Bob <span class=\"star-count\">4*</span></div>\
+            <a href=\"/2024/auth/logout\">[Log Out]</a></header>";
+        let (client, request) = serve("200 OK", html);
+        assert_eq!(client.get_user().unwrap(), "Alice & Bob");
+        assert!(request.recv().unwrap().starts_with("GET / HTTP/1.1"));
+
+        let (client, _) = serve("200 OK", "<div class=\"user\">(anonymous user #42)</div>");
+        assert_eq!(client.get_user().unwrap(), "(anonymous user #42)");
+    }
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(
+            strip_tags("<p>That's not the right answer&#39;s <em>value</em>.</p>"),
+            "That's not the right answer's value."
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Module that moves files of the layout without years into the folders of a year and switches the default year.
+
+use crate::template::{Day, Year};
+
+/// Folders of `./data` that hold a file per day.
+pub const DATA_FOLDERS: [&str; 3] = ["inputs", "examples", "puzzles"];
+
+/// Returns the day of a solution that is not namespaced by a year yet, e.g. `src/bin/01.rs`.
+pub fn get_legacy_day(file_name: &str) -> Option<Day> {
+    let stem = file_name.strip_suffix(".rs")?;
+    if stem.len() != 2 {
+        return None;
+    }
+    stem.parse().ok()
+}
+
+/// Rewrites a solution that is not namespaced by a year, i.e. `solution!(1)` becomes `solution!(2024, 1)`
+/// and its tests read the example files of `PUZZLE` instead of `DAY`.
+pub fn migrate_solution(module: &str, year: Year) -> String {
+    module
+        .replace("solution!(", &format!("solution!({year}, "))
+        .replace("DAY, 1)", "PUZZLE, advent_of_code::template::Part::One)")
+        .replace("DAY, 2)", "PUZZLE, advent_of_code::template::Part::Two)")
+        .replace("DAY)", "PUZZLE)")
+}
+
+/// Sets `year` in the contents of `aoc.toml`, adding it before the first table if it is not set yet.
+pub fn set_config_year(config: &str, year: Year) -> String {
+    let setting = format!("year = {year}");
+    let mut lines: Vec<String> = config.lines().map(String::from).collect();
+
+    // settings after the first table belong to that table.
+    let top_level = lines
+        .iter()
+        .position(|x| x.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+
+    let existing = lines[..top_level].iter().position(|x| {
+        x.split_once('=')
+            .is_some_and(|(key, _)| key.trim() == "year")
+    });
+
+    match existing {
+        Some(index) => lines[index] = setting,
+        None => lines.insert(top_level, setting),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_legacy_day, migrate_solution, set_config_year};
+    use crate::{day, year};
+
+    #[test]
+    fn finds_legacy_solutions() {
+        assert_eq!(get_legacy_day("01.rs"), Some(day!(1)));
+        assert_eq!(get_legacy_day("25.rs"), Some(day!(25)));
+        assert_eq!(get_legacy_day("2024_01.rs"), None);
+        assert_eq!(get_legacy_day("1.rs"), None);
+        assert_eq!(get_legacy_day("01.txt"), None);
+    }
+
+    #[test]
+    fn migrates_solutions() {
+        let module = [
+            "advent_of_code::solution!(3);",
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "let result = part_two(&advent_of_code::template::read_file_part(\"examples\", DAY, 2));",
+        ]
+        .join("\n");
+
+        let expected = [
+            "advent_of_code::solution!(2023, 3);",
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "let result = part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, advent_of_code::template::Part::Two));",
+        ]
+        .join("\n");
+
+        assert_eq!(migrate_solution(&module, year!(2023)), expected);
+    }
+
+    #[test]
+    fn sets_config_year() {
+        let config = "# the default year\nyear = 2024\n\n[runner]\ncolor = false\n";
+        assert_eq!(
+            set_config_year(config, year!(2025)),
+            "# the default year\nyear = 2025\n\n[runner]\ncolor = false\n"
+        );
+        assert_eq!(
+            set_config_year("[paths]\nyear = \"x\"\n", year!(2025)),
+            "year = 2025\n[paths]\nyear = \"x\"\n"
+        );
+        assert_eq!(set_config_year("", year!(2025)), "year = 2025\n");
+    }
+}
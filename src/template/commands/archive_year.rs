@@ -0,0 +1,138 @@
+use std::{fs, path::Path, process};
+
+use crate::template::archive::{get_legacy_day, migrate_solution, set_config_year, DATA_FOLDERS};
+use crate::template::config::config_path;
+use crate::template::run_multi::{get_bin_dir, get_path_for_bin};
+use crate::template::{
+    create_data_file, data_dir, get_data_path, readme_benchmarks, PuzzleId, Year,
+};
+
+pub fn handle(year: Year, next: Year) {
+    if next <= year {
+        eprintln!("The next year has to come after {year}.");
+        process::exit(1);
+    }
+
+    let archive_path = get_data_path(year, "README.md");
+    if Path::new(&archive_path).exists() {
+        eprintln!("{year} was already archived to \"{archive_path}\".");
+        process::exit(1);
+    }
+
+    // a missing config is created with the next year.
+    let config_path = config_path();
+    let config = set_config_year(&fs::read_to_string(&config_path).unwrap_or_default(), next);
+
+    migrate_solutions(year);
+    migrate_data(year);
+
+    match readme_benchmarks::archive(year, next) {
+        Ok(path) => println!("Moved the readme sections of {year} to \"{path}\"."),
+        Err(e) => {
+            eprintln!("Failed to archive the readme sections: {e:?}");
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = fs::write(&config_path, config) {
+        eprintln!("Failed to write \"{}\": {e}", config_path.display());
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Archived {year}, the default year is {next} now.");
+
+    if let Some(env_year) = Year::from_env() {
+        println!(
+            "Note: `AOC_YEAR` is set to {env_year} and takes precedence over \"{}\".",
+            config_path.display()
+        );
+    }
+}
+
+/// Moves solutions that are not namespaced by a year, e.g. `src/bin/01.rs`, to the year.
+fn migrate_solutions(year: Year) {
+    let Ok(entries) = fs::read_dir(get_bin_dir()) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(day) = get_legacy_day(&file_name) else {
+            continue;
+        };
+
+        let source = entry.path();
+        let target = get_path_for_bin(PuzzleId::new(year, day));
+
+        if Path::new(&target).exists() {
+            eprintln!("Skipped \"{}\", \"{target}\" exists.", source.display());
+            continue;
+        }
+
+        let result = fs::read_to_string(&source)
+            .and_then(|module| fs::write(&target, migrate_solution(&module, year)))
+            .and_then(|()| fs::remove_file(&source));
+
+        match result {
+            Ok(()) => println!("Moved \"{}\" to \"{target}\".", source.display()),
+            Err(e) => {
+                eprintln!("Failed to move \"{}\": {e}", source.display());
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Moves data that is not namespaced by a year, e.g. `data/inputs/01.txt` or `data/timings.json`, to the year.
+fn migrate_data(year: Year) {
+    let data = data_dir();
+    let mut files = vec![];
+
+    for folder in DATA_FOLDERS {
+        if let Ok(entries) = fs::read_dir(data.join(folder)) {
+            files.extend(entries.flatten().map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                (
+                    entry.path(),
+                    get_data_path(year, &format!("{folder}/{name}")),
+                )
+            }));
+        }
+    }
+
+    if let Ok(entries) = fs::read_dir(&data) {
+        files.extend(
+            entries
+                .flatten()
+                .filter(|entry| entry.path().extension().is_some_and(|x| x == "json"))
+                .map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    (entry.path(), get_data_path(year, &name))
+                }),
+        );
+    }
+
+    for (source, target) in files {
+        if Path::new(&target).exists() {
+            eprintln!("Skipped \"{}\", \"{target}\" exists.", source.display());
+            continue;
+        }
+
+        // creates the folder of the year.
+        let result = create_data_file(&target).and_then(|_| fs::rename(&source, &target));
+
+        match result {
+            Ok(()) => println!("Moved \"{}\" to \"{target}\".", source.display()),
+            Err(e) => {
+                eprintln!("Failed to move \"{}\": {e}", source.display());
+                process::exit(1);
+            }
+        }
+    }
+
+    for folder in DATA_FOLDERS {
+        // only succeeds for folders that are empty now.
+        let _ = fs::remove_dir(data.join(folder));
+    }
+}
@@ -1,14 +1,15 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client, PuzzleId};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId, puzzle_only: bool) {
+    let result = if puzzle_only {
+        aoc_client::download_puzzle(puzzle)
+    } else {
+        aoc_client::download(puzzle)
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = result {
+        eprintln!("failed to download puzzle {puzzle}: {e}");
         process::exit(1);
     };
 }
@@ -0,0 +1,156 @@
+use std::{
+    fs,
+    io::{stdin, stdout, IsTerminal, Write},
+    process,
+};
+
+use crate::template::encryption;
+use crate::template::examples::{fill_test, find_candidates};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{get_data_path, Part, PuzzleId};
+
+pub fn handle(puzzle: PuzzleId) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let puzzle_path = get_data_path(year, &format!("puzzles/{day}.md"));
+    let module_path = get_path_for_bin(puzzle);
+
+    let Ok(description) = encryption::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {puzzle}` first.");
+        process::exit(1);
+    };
+
+    let Ok(original) = fs::read_to_string(&module_path) else {
+        eprintln!("Could not read \"{module_path}\". Run `cargo scaffold {puzzle}` first.");
+        process::exit(1);
+    };
+
+    let mut module = original.clone();
+
+    let (part_1, part_2) = find_candidates(&description);
+
+    let example_1 = choose(
+        "example of part one",
+        &part_1.inputs,
+        longest(&part_1.inputs),
+    );
+    if let Some(example) = example_1 {
+        write_example(
+            &get_data_path(year, &format!("examples/{day}.txt")),
+            example,
+        );
+    }
+
+    if let Some(answer) = choose("answer of part one", &part_1.answers, last(&part_1.answers)) {
+        fill_module(&mut module, Part::One, answer, None);
+    }
+
+    // part two usually continues with the example of part one.
+    let mut example_part = None;
+    if let Some(example) = choose(
+        "example of part two",
+        &part_2.inputs,
+        longest(&part_2.inputs),
+    ) {
+        if Some(example) != example_1 {
+            write_example(
+                &get_data_path(year, &format!("examples/{day}-2.txt")),
+                example,
+            );
+            example_part = Some(Part::Two);
+        }
+    }
+
+    if let Some(answer) = choose("answer of part two", &part_2.answers, last(&part_2.answers)) {
+        fill_module(&mut module, Part::Two, answer, example_part);
+    }
+
+    if module == original {
+        return;
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
+
+/// Lets the user choose between several candidates if running in a terminal, otherwise picks the default.
+fn choose<'a>(label: &str, candidates: &'a [String], default: usize) -> Option<&'a String> {
+    if candidates.len() <= 1 || !stdin().is_terminal() {
+        return candidates.get(default);
+    }
+
+    println!("Found {} candidates for the {label}:", candidates.len());
+    for (i, candidate) in candidates.iter().enumerate() {
+        println!("  [{}] {}", i + 1, preview(candidate));
+    }
+    print!(
+        "Choose one of 1-{} (default {}): ",
+        candidates.len(),
+        default + 1
+    );
+    let _ = stdout().flush();
+
+    let mut choice = String::new();
+    let _ = stdin().read_line(&mut choice);
+
+    choice
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|x| candidates.get(x.checked_sub(1)?))
+        .or(candidates.get(default))
+}
+
+fn preview(candidate: &str) -> String {
+    let lines = candidate.lines().count();
+    let first_line: String = candidate
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .take(40)
+        .collect();
+
+    if lines > 1 {
+        format!("{first_line} ... ({lines} lines)")
+    } else {
+        first_line
+    }
+}
+
+/// The longest code block is most likely the full example rather than an excerpt.
+fn longest(candidates: &[String]) -> usize {
+    candidates
+        .iter()
+        .enumerate()
+        .max_by_key(|(i, x)| (x.len(), std::cmp::Reverse(*i)))
+        .map_or(0, |(i, _)| i)
+}
+
+/// The answer of the example is usually the last highlighted value.
+fn last(candidates: &[String]) -> usize {
+    candidates.len().saturating_sub(1)
+}
+
+fn write_example(path: &str, example: &str) {
+    if fs::read_to_string(path).is_ok_and(|x| !x.trim().is_empty()) {
+        println!("Skipped \"{path}\", it already has contents.");
+        return;
+    }
+
+    match fs::write(path, example) {
+        Ok(()) => println!("🎄 Wrote example to \"{path}\"."),
+        Err(e) => eprintln!("Failed to write example file: {e}"),
+    }
+}
+
+fn fill_module(module: &mut String, part: Part, answer: &str, example_part: Option<Part>) {
+    match fill_test(module, part, answer, example_part) {
+        Some(filled) => {
+            *module = filled;
+            println!("🎄 Expecting {answer} in the test of part {part}.");
+        }
+        None => println!("Skipped the test of part {part}, it already has an expected value."),
+    }
+}
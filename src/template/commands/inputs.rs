@@ -0,0 +1,126 @@
+use std::{fs, process};
+
+use crate::template::encryption::{encrypted_path, Key, ENCRYPTED_EXTENSION};
+use crate::template::{data_dir, Year};
+
+/// Folders of a year whose files are encrypted.
+const ENCRYPTED_FOLDERS: [&str; 2] = ["inputs", "puzzles"];
+
+pub enum Action {
+    Encrypt,
+    Decrypt,
+}
+
+/// Encrypts or decrypts the inputs and puzzles of a year, or of every year in the data folder.
+pub fn handle(action: Action, year: Option<Year>) {
+    let key = match Key::load() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to load the key: {e}.");
+            process::exit(1);
+        }
+    };
+
+    let years = match year {
+        Some(year) => vec![year],
+        None => find_years(),
+    };
+
+    let (mut written, mut unchanged) = (0, 0);
+
+    for path in years.into_iter().flat_map(files_of_year) {
+        let result = match action {
+            Action::Encrypt => encrypt_file(&key, &path),
+            Action::Decrypt => decrypt_file(&key, &path),
+        };
+
+        match result {
+            Ok(true) => written += 1,
+            Ok(false) => unchanged += 1,
+            Err(e) => {
+                eprintln!("Failed to process \"{path}\": {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    match action {
+        Action::Encrypt => println!("🔒 Encrypted {written} files, {unchanged} were up to date."),
+        Action::Decrypt => println!("🔓 Decrypted {written} files, {unchanged} were up to date."),
+    }
+}
+
+/// Returns the years that have a folder in the data folder.
+fn find_years() -> Vec<Year> {
+    let mut years: Vec<Year> = fs::read_dir(data_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    years.sort_unstable();
+    years
+}
+
+/// Returns the plain paths of the files of a year that have a plain or an encrypted version.
+fn files_of_year(year: Year) -> Vec<String> {
+    let mut paths: Vec<String> = ENCRYPTED_FOLDERS
+        .iter()
+        .filter_map(|folder| fs::read_dir(data_dir().join(year.to_string()).join(folder)).ok())
+        .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+        .filter_map(|path| {
+            let path = path.to_str()?;
+            let plain = path.strip_suffix(ENCRYPTED_EXTENSION).unwrap_or(path);
+            (plain.ends_with(".txt") || plain.ends_with(".md")).then(|| plain.to_string())
+        })
+        .collect();
+
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+}
+
+/// Writes the encrypted version of a file. Returns whether it changed.
+fn encrypt_file(key: &Key, path: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    let Ok(plain) = fs::read(path) else {
+        // only the encrypted version exists.
+        return Ok(false);
+    };
+
+    let target = encrypted_path(path);
+    let encrypted = key.encrypt(&plain);
+
+    if fs::read(&target).is_ok_and(|x| x == encrypted) {
+        return Ok(false);
+    }
+
+    fs::write(&target, encrypted)?;
+    println!("Encrypted \"{path}\".");
+    Ok(true)
+}
+
+/// Writes the plain version of an encrypted file. Returns whether it changed.
+/// Plain files that differ from the encrypted version are kept, since they might have been edited by hand.
+fn decrypt_file(key: &Key, path: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    let Ok(encrypted) = fs::read(encrypted_path(path)) else {
+        return Ok(false);
+    };
+
+    let plain = key.decrypt(&encrypted)?;
+
+    match fs::read(path) {
+        Ok(existing) if existing == plain => Ok(false),
+        Ok(_) => {
+            eprintln!("Skipped \"{path}\", it differs from its encrypted version. Delete it to restore it.");
+            Ok(false)
+        }
+        Err(_) => {
+            fs::write(path, plain)?;
+            println!("Decrypted \"{path}\".");
+            Ok(true)
+        }
+    }
+}
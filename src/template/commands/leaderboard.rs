@@ -0,0 +1,41 @@
+use std::process;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::leaderboard::{Leaderboard, MARKER};
+use crate::template::{readme_benchmarks, Year};
+
+pub fn handle(year: Year, id: &str, update_readme: bool) {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        eprintln!("Leaderboard id \"{id}\" is invalid, expected the number in its url.");
+        process::exit(1);
+    }
+
+    let leaderboard = AocClient::from_env()
+        .and_then(|client| client.get_leaderboard(year, id))
+        .map_err(|e| e.to_string())
+        .and_then(|json| Leaderboard::try_from(&json));
+
+    let leaderboard = match leaderboard {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("failed to fetch leaderboard {id}: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{}", leaderboard.format_terminal());
+
+    if update_readme {
+        match readme_benchmarks::update_section(MARKER, &leaderboard.format_markdown()) {
+            Ok(()) => println!("\nStored leaderboard in readme."),
+            Err(readme_benchmarks::Error::Parser(_)) => {
+                eprintln!("Could not find the leaderboard markers in README.md, add `{MARKER}` twice where it should go.");
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to store leaderboard in readme. {e:?}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,6 +1,15 @@
 pub mod all;
+pub mod archive_year;
 pub mod download;
+pub mod examples;
+pub mod inputs;
+pub mod leaderboard;
 pub mod read;
+pub mod report;
 pub mod scaffold;
+pub mod session;
 pub mod solve;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -0,0 +1,39 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::history::History;
+use crate::template::report::render;
+use crate::template::timings::{is_valid_table_name, Timings};
+use crate::template::{ReportFormat, Year};
+
+pub fn handle(year: Year, format: ReportFormat, out: Option<PathBuf>, table: Option<&str>) {
+    if let Some(name) = table {
+        if !is_valid_table_name(name) {
+            eprintln!("Invalid table name \"{name}\": use letters, digits, `-` and `_` only.");
+            process::exit(1);
+        }
+    }
+
+    let timings = Timings::read_from_file(year, table);
+
+    if timings.data.is_empty() {
+        eprintln!("No stored timings found. Run `cargo time --store` first.");
+        process::exit(1);
+    }
+
+    let history = History::read_from_file(year, table);
+    let mut report = render(timings, year, table, &history, format);
+    if !report.ends_with('\n') {
+        report.push('\n');
+    }
+
+    match out {
+        Some(path) => match fs::write(&path, report) {
+            Ok(()) => println!("🎄 Wrote report to \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write report: {e}");
+                process::exit(1);
+            }
+        },
+        None => print!("{report}"),
+    }
+}
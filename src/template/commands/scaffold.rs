@@ -1,13 +1,13 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::config::project_root;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{config, get_data_path, PuzzleId};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -20,6 +20,11 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    // the data folders of a year do not exist before its first puzzle.
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +32,23 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let input_path = get_data_path(year, &format!("inputs/{day}.txt"));
+    let example_path = get_data_path(year, &format!("examples/{day}.txt"));
+    let module_path = get_path_for_bin(puzzle);
+
+    let template_path = project_root().join(&config().paths.template);
+    let module_template = match fs::read_to_string(&template_path) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!(
+                "Failed to read the module template \"{}\": {e}",
+                template_path.display()
+            );
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -41,7 +59,8 @@ pub fn handle(day: Day, overwrite: bool) {
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
+        module_template
+            .replace("%YEAR%", &year.to_string())
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -75,5 +94,5 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {puzzle}` to run your solution.");
 }
@@ -0,0 +1,16 @@
+use std::process;
+
+use crate::template::aoc_client::{self, AocClient};
+
+/// Validates the session cookie of the selected profile by fetching the name of its user.
+pub fn check() {
+    let profile = aoc_client::get_profile().unwrap_or_else(|| "default".into());
+
+    match AocClient::from_env().and_then(|client| client.get_user()) {
+        Ok(user) => println!("🎄 Session of profile \"{profile}\" is valid, logged in as {user}."),
+        Err(e) => {
+            eprintln!("❌ Session of profile \"{profile}\" is not valid: {e}");
+            process::exit(1);
+        }
+    }
+}
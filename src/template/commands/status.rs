@@ -0,0 +1,36 @@
+use std::process::{Command, Stdio};
+
+use crate::template::answers::Answers;
+use crate::template::status::{format_calendar, DayStatus};
+use crate::template::timings::Timings;
+use crate::template::{all_days, PuzzleId, Year};
+
+pub fn handle(year: Year, run_tests: bool) {
+    let answers = Answers::read_from_file(year);
+    let timings = Timings::read_from_file(year, None);
+
+    let mut days: Vec<DayStatus> = all_days(year)
+        .map(|day| DayStatus::read(PuzzleId::new(year, day), &answers, &timings))
+        .collect();
+
+    if run_tests {
+        for status in days.iter_mut().filter(|x| x.scaffolded) {
+            let puzzle = PuzzleId::new(year, status.day);
+            println!("Running the tests of {puzzle}...");
+            status.tests = Some(run_tests_of(puzzle));
+        }
+        println!();
+    }
+
+    println!("{}", format_calendar(year, &days));
+}
+
+/// Runs the tests of a solution, which read its example files.
+fn run_tests_of(puzzle: PuzzleId) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &puzzle.bin_name()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|x| x.success())
+}
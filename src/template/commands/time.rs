@@ -1,19 +1,39 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::answers::Answers;
+use crate::template::history::History;
+use crate::template::readme_benchmarks::Trend;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::style;
+use crate::template::timings::{is_valid_table_name, MergePolicy, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, Part, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    table: Option<&str>,
+    policy: MergePolicy,
+    trend_len: usize,
+) {
+    if let Some(name) = table {
+        if !is_valid_table_name(name) {
+            eprintln!("Invalid table name \"{name}\": use letters, digits, `-` and `_` only.");
+            process::exit(1);
+        }
+    }
+
+    let stored_timings = Timings::read_from_file(year, table);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +41,58 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(year, &days_to_run, true, true).unwrap();
+
+    // the trend includes this run, but only stored runs are kept in the history.
+    let mut history = History::read_from_file(year, table);
+    history.record(&timings);
+    if store {
+        if let Err(e) = history.store_file(year, table) {
+            eprintln!("Failed to store benchmark history: {e}");
+        }
+    }
+
+    let trend = Trend {
+        history: &history,
+        len: trend_len,
+    };
+    print_trends(&timings, &trend);
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        let answers = Answers::read_from_file(year);
+        let merged_timings = stored_timings.merge(&timings, policy, &answers);
+        merged_timings.store_file(year, table).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, table, merged_timings, &trend) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e:?}");
             }
         }
     }
 }
+
+fn print_trends(timings: &Timings, trend: &Trend) {
+    let lines: Vec<String> = timings
+        .data
+        .iter()
+        .flat_map(|timing| Part::ALL.map(|part| (timing.day, part)))
+        .filter_map(|(day, part)| {
+            let line = trend.history.format_trend(day, part, trend.len)?;
+            Some(format!("Day {day} Part {part}: {line}"))
+        })
+        .collect();
+
+    if !lines.is_empty() {
+        println!(
+            "\n{}Trend (last {} runs):{}",
+            style(ANSI_BOLD),
+            trend.len,
+            style(ANSI_RESET)
+        );
+        println!("{}", lines.join("\n"));
+    }
+}
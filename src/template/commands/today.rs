@@ -0,0 +1,102 @@
+use std::{
+    io::{stdout, Write},
+    path::Path,
+    process, thread,
+    time::Duration,
+};
+
+use chrono::Utc;
+
+use crate::template::aoc_client::{self, AocCommandError};
+use crate::template::commands::{examples, read, scaffold};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::PuzzleId;
+
+/// Extra seconds to wait after the unlock, as the puzzle might not be served right away.
+const UNLOCK_DELAY_SECS: u64 = 2;
+
+/// The download is retried this many times, doubling the delay from [`RETRY_DELAY_SECS`] after each attempt.
+const DOWNLOAD_RETRIES: u32 = 5;
+const RETRY_DELAY_SECS: u64 = 2;
+
+pub fn handle(wait: bool) {
+    let puzzle = match (PuzzleId::today(), wait) {
+        (Some(puzzle), false) => puzzle,
+        // today's puzzle is already unlocked but might not have been scaffolded yet.
+        (Some(puzzle), true) if !Path::new(&get_path_for_bin(puzzle)).exists() => puzzle,
+        (_, true) => wait_for_unlock(),
+        (None, false) => {
+            eprintln!(
+                "`today` command can only be run during an event, from the 1st of \
+                december to its last day. Please use `scaffold` with a specific day \
+                or append `--wait` to wait for the next puzzle."
+            );
+            process::exit(1)
+        }
+    };
+
+    scaffold::handle(puzzle, false);
+    download(puzzle);
+    examples::handle(puzzle);
+    read::handle(puzzle);
+}
+
+/// Shows a countdown until the next puzzle unlocks and returns it.
+fn wait_for_unlock() -> PuzzleId {
+    let Some((puzzle, unlock)) = PuzzleId::next_unlock() else {
+        eprintln!("Could not determine when the next puzzle unlocks.");
+        process::exit(1);
+    };
+
+    println!(
+        "🎄 Puzzle {puzzle} unlocks at {}.",
+        unlock.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+
+    loop {
+        let remaining = (unlock - Utc::now()).num_seconds();
+        if remaining <= 0 {
+            break;
+        }
+
+        print!("\r⏳ Unlocks in {}... ", format_countdown(remaining));
+        let _ = stdout().flush();
+        thread::sleep(Duration::from_secs(1));
+    }
+
+    println!();
+    thread::sleep(Duration::from_secs(UNLOCK_DELAY_SECS));
+    puzzle
+}
+
+fn format_countdown(secs: i64) -> String {
+    let (days, hours, minutes, secs) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{secs:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{secs:02}")
+    }
+}
+
+/// Downloads the input, retrying with backoff while the website does not serve it yet.
+fn download(puzzle: PuzzleId) {
+    let mut delay = RETRY_DELAY_SECS;
+
+    for attempt in 0..=DOWNLOAD_RETRIES {
+        match aoc_client::download(puzzle) {
+            Ok(()) => return,
+            Err(e @ (AocCommandError::HttpStatus(_) | AocCommandError::Transport(_)))
+                if attempt < DOWNLOAD_RETRIES =>
+            {
+                eprintln!("failed to download puzzle {puzzle}: {e} Retrying in {delay}s...");
+                thread::sleep(Duration::from_secs(delay));
+                delay *= 2;
+            }
+            Err(e) => {
+                eprintln!("failed to download puzzle {puzzle}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,598 @@
+//! Module that reads the project configuration from `aoc.toml`.
+//!
+//! Only the part of TOML that the configuration needs is supported: tables, comments,
+//! and keys with string, integer or boolean values.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::template::{Year, MAX_DAY};
+
+/// Name of the configuration file in the root of the project.
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// When this environment variable is set, it replaces the data folder of `aoc.toml`.
+pub const DATA_DIR_ENV_VAR: &str = "AOC_DATA_DIR";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+static PROJECT_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// The project configuration. Every setting is optional and falls back to its [`Default`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The year commands work on unless they are given one. `AOC_YEAR` takes precedence.
+    pub year: Option<Year>,
+    pub paths: Paths,
+    pub runner: Runner,
+    pub client: Client,
+    pub input: Input,
+    /// Number of days of events that deviate from [`Year::default_days`], by year.
+    pub days: BTreeMap<Year, u8>,
+}
+
+/// Locations of the files the commands read and write, relative to the root of the project.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paths {
+    /// Folder of the inputs, examples, puzzles and stores, with a subfolder per year.
+    pub data: String,
+    /// Readme with the benchmark tables and template regions.
+    pub readme: String,
+    /// Template of new solutions.
+    pub template: String,
+    /// File name of the timings in the folder of a year. Named tables add their name, e.g. `timings-laptop.json`.
+    pub timings: String,
+}
+
+/// Defaults of the solution runner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Runner {
+    /// Approximate time spent on benchmarking a part.
+    pub bench_budget: Duration,
+    pub min_samples: u32,
+    pub max_samples: u32,
+    /// Highlight results with ANSI escape codes.
+    pub color: bool,
+}
+
+/// Settings of the Advent of Code client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Client {
+    pub timeout: Duration,
+    /// Identifies the owner of the project in the `User-Agent` of requests, e.g. a repository url or an email.
+    pub contact: Option<String>,
+}
+
+/// Policies that `read_file` applies to inputs and examples before handing them to a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    /// Replace Windows line endings (`\r\n`) with `\n`.
+    pub normalize_line_endings: bool,
+    /// Remove the newlines at the end of the file.
+    pub trim_trailing_newline: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            paths: Paths {
+                data: "data".into(),
+                readme: "README.md".into(),
+                template: "src/template.txt".into(),
+                timings: "timings.json".into(),
+            },
+            runner: Runner {
+                bench_budget: Duration::from_secs(1),
+                min_samples: 10,
+                max_samples: 10000,
+                color: true,
+            },
+            client: Client {
+                timeout: Duration::from_secs(30),
+                contact: None,
+            },
+            input: Input {
+                normalize_line_endings: true,
+                trim_trailing_newline: false,
+            },
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+/// Returns the configuration, reading it on first use.
+/// Panics if `aoc.toml` is invalid. Commands check it up front with [`load_config`], so that they can rely on its values.
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        Config::read_from_file().unwrap_or_else(|e| panic!("invalid {CONFIG_FILE_NAME}: {e}"))
+    })
+}
+
+/// Reads the configuration, unless it was read already, and returns it. Fails if `aoc.toml` is invalid.
+pub fn load_config() -> Result<&'static Config, ConfigError> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let config = Config::read_from_file()?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Returns the root of the project, which holds `aoc.toml` and the data folder.
+///
+/// `cargo run` and `cargo test` set `CARGO_MANIFEST_DIR`. Binaries started in another way, e.g. by a debugger,
+/// search upward from the working directory and fall back to the folder the template was built in.
+pub fn project_root() -> &'static Path {
+    PROJECT_ROOT.get_or_init(|| {
+        if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR").filter(|x| !x.is_empty()) {
+            return PathBuf::from(dir);
+        }
+
+        env::current_dir()
+            .ok()
+            .and_then(|cwd| search_upward(&cwd).map(Path::to_path_buf))
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+    })
+}
+
+/// Returns the closest folder at or above `start` that contains `aoc.toml` or a `Cargo.toml`.
+fn search_upward(start: &Path) -> Option<&Path> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(CONFIG_FILE_NAME).is_file() || dir.join("Cargo.toml").is_file())
+}
+
+/// Returns the path of `aoc.toml` in the root of the project.
+pub fn config_path() -> PathBuf {
+    project_root().join(CONFIG_FILE_NAME)
+}
+
+/// Returns the data folder: `AOC_DATA_DIR` if set, otherwise `paths.data`. Both are relative to the root of the project,
+/// so that the build script and the binaries agree on the folder, see the `embed_inputs` feature.
+/// The result is relative to the working directory if it is within it, to keep paths in messages short.
+pub fn data_dir() -> PathBuf {
+    let dir = match env::var_os(DATA_DIR_ENV_VAR).filter(|x| !x.is_empty()) {
+        Some(dir) => project_root().join(dir),
+        None => project_root().join(&config().paths.data),
+    };
+
+    relative_to_cwd(dir)
+}
+
+/// Returns `path` relative to the working directory if it is within it, and unchanged otherwise.
+pub(crate) fn relative_to_cwd(path: PathBuf) -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or(path)
+}
+
+impl Config {
+    /// Reads `aoc.toml` from the root of the project. If not present, returns the default configuration.
+    pub fn read_from_file() -> Result<Self, ConfigError> {
+        match fs::read_to_string(config_path()) {
+            Ok(s) => Config::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(ConfigError::IO(e)),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ConfigError> {
+        let mut config = Config::default();
+        let mut table = String::new();
+
+        for (index, raw) in s.lines().enumerate() {
+            let line = index + 1;
+            let content = strip_comment(raw).trim();
+
+            if content.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = content.strip_prefix('[') {
+                table = name
+                    .strip_suffix(']')
+                    .map(|x| x.trim().to_string())
+                    .ok_or_else(|| ConfigError::Syntax {
+                        line,
+                        message: "expected `]` after the table name".into(),
+                    })?;
+
+                if !matches!(
+                    table.as_str(),
+                    "paths" | "runner" | "client" | "input" | "days"
+                ) {
+                    return Err(ConfigError::UnknownTable { line, table });
+                }
+                continue;
+            }
+
+            let (key, value) = content.split_once('=').ok_or_else(|| ConfigError::Syntax {
+                line,
+                message: "expected `key = value`".into(),
+            })?;
+
+            let key = key.trim();
+            let value = parse_value(value.trim()).ok_or_else(|| ConfigError::Syntax {
+                line,
+                message: format!("could not read the value of `{key}`"),
+            })?;
+
+            config.set(&table, key, value, line)?;
+        }
+
+        config.check()?;
+        Ok(config)
+    }
+
+    fn set(
+        &mut self,
+        table: &str,
+        key: &str,
+        value: Value,
+        line: usize,
+    ) -> Result<(), ConfigError> {
+        let invalid = |expected: &'static str| ConfigError::InvalidValue {
+            line,
+            key: key.to_string(),
+            expected,
+        };
+
+        match (table, key) {
+            ("", "year") => {
+                let year = value
+                    .as_integer()
+                    .and_then(|x| u16::try_from(x).ok())
+                    .and_then(Year::new);
+                self.year = Some(year.ok_or_else(|| invalid("a year of 2015 or later"))?);
+            }
+            ("paths", "data" | "readme" | "template" | "timings") => {
+                let path = value
+                    .into_string()
+                    .filter(|x| !x.is_empty())
+                    .ok_or_else(|| invalid("a non-empty string"))?;
+
+                match key {
+                    "data" => self.paths.data = path,
+                    "readme" => self.paths.readme = path,
+                    "template" => self.paths.template = path,
+                    _ => self.paths.timings = path,
+                }
+            }
+            ("runner", "bench_budget_ms") => {
+                let millis = value
+                    .as_integer()
+                    .and_then(|x| u64::try_from(x).ok())
+                    .filter(|x| *x > 0)
+                    .ok_or_else(|| invalid("a positive number of milliseconds"))?;
+                self.runner.bench_budget = Duration::from_millis(millis);
+            }
+            ("runner", "min_samples" | "max_samples") => {
+                let samples = value
+                    .as_integer()
+                    .and_then(|x| u32::try_from(x).ok())
+                    .filter(|x| *x > 0)
+                    .ok_or_else(|| invalid("a positive number of samples"))?;
+
+                if key == "min_samples" {
+                    self.runner.min_samples = samples;
+                } else {
+                    self.runner.max_samples = samples;
+                }
+            }
+            ("runner", "color") => {
+                self.runner.color = value
+                    .as_bool()
+                    .ok_or_else(|| invalid("`true` or `false`"))?;
+            }
+            ("input", "normalize_line_endings" | "trim_trailing_newline") => {
+                let enabled = value
+                    .as_bool()
+                    .ok_or_else(|| invalid("`true` or `false`"))?;
+
+                if key == "normalize_line_endings" {
+                    self.input.normalize_line_endings = enabled;
+                } else {
+                    self.input.trim_trailing_newline = enabled;
+                }
+            }
+            ("client", "timeout_secs") => {
+                let secs = value
+                    .as_integer()
+                    .and_then(|x| u64::try_from(x).ok())
+                    .filter(|x| *x > 0)
+                    .ok_or_else(|| invalid("a positive number of seconds"))?;
+                self.client.timeout = Duration::from_secs(secs);
+            }
+            ("client", "contact") => {
+                let contact = value
+                    .into_string()
+                    .filter(|x| !x.trim().is_empty() && !x.contains(['\r', '\n']))
+                    .ok_or_else(|| invalid("a url or an email on a single line"))?;
+                self.client.contact = Some(contact);
+            }
+            ("days", _) if key.parse::<Year>().is_ok() => {
+                let days = value
+                    .as_integer()
+                    .and_then(|x| u8::try_from(x).ok())
+                    .filter(|x| (1..=MAX_DAY).contains(x))
+                    .ok_or_else(|| invalid("a number of days from 1 to 25"))?;
+                self.days.insert(key.parse().unwrap(), days);
+            }
+            _ => {
+                return Err(ConfigError::UnknownKey {
+                    line,
+                    key: if table.is_empty() {
+                        key.to_string()
+                    } else {
+                        format!("{table}.{key}")
+                    },
+                })
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the settings that depend on each other.
+    fn check(&self) -> Result<(), ConfigError> {
+        if self.runner.min_samples > self.runner.max_samples {
+            return Err(ConfigError::Conflict(
+                "`runner.min_samples` is larger than `runner.max_samples`".into(),
+            ));
+        }
+
+        // the history of a table is stored next to its timings, e.g. `timings.history.json`.
+        if !self.paths.timings.ends_with(".json") {
+            return Err(ConfigError::Conflict(
+                "`paths.timings` has to end with `.json`".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Bool(bool),
+}
+
+impl Value {
+    fn into_string(self) -> Option<String> {
+        match self {
+            Value::String(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+fn parse_value(s: &str) -> Option<Value> {
+    match s {
+        "true" => return Some(Value::Bool(true)),
+        "false" => return Some(Value::Bool(false)),
+        _ => {}
+    }
+
+    if let Some(rest) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return chars.as_str().is_empty().then_some(Value::String(value)),
+                '\\' => value.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    c @ ('"' | '\\') => c,
+                    _ => return None,
+                }),
+                c => value.push(c),
+            }
+        }
+
+        return None;
+    }
+
+    s.replace('_', "").parse().ok().map(Value::Integer)
+}
+
+/// Removes a trailing comment, ignoring `#` within strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => {
+                escaped = !escaped;
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+
+    line
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(io::Error),
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    UnknownTable {
+        line: usize,
+        table: String,
+    },
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    InvalidValue {
+        line: usize,
+        key: String,
+        expected: &'static str,
+    },
+    Conflict(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO(e) => write!(f, "could not read the file: {e}"),
+            ConfigError::Syntax { line, message } => write!(f, "line {line}: {message}."),
+            ConfigError::UnknownTable { line, table } => {
+                write!(f, "line {line}: unknown table `[{table}]`.")
+            }
+            ConfigError::UnknownKey { line, key } => write!(f, "line {line}: unknown key `{key}`."),
+            ConfigError::InvalidValue {
+                line,
+                key,
+                expected,
+            } => write!(f, "line {line}: expected `{key}` to be {expected}."),
+            ConfigError::Conflict(message) => write!(f, "{message}."),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use super::{search_upward, Config, ConfigError};
+    use crate::year;
+
+    #[test]
+    fn finds_project_root() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(search_upward(&root.join("src/template")), Some(root));
+        assert_eq!(search_upward(root), Some(root));
+    }
+
+    #[test]
+    fn parses_config() {
+        let config = Config::parse(
+            r#"
+            # comments are ignored.
+            year = 2023
+
+            [paths]
+            data = "puzzles # and more" # trailing comment
+            timings = "bench.json"
+
+            [runner]
+            bench_budget_ms = 2_000
+            color = false
+
+            [client]
+            timeout_secs = 5
+            contact = "github.com/someone/advent-of-code"
+
+            [input]
+            trim_trailing_newline = true
+
+            [days]
+            2016 = 3
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2023)));
+        assert_eq!(config.paths.data, "puzzles # and more");
+        assert_eq!(config.paths.readme, "README.md");
+        assert_eq!(config.paths.timings, "bench.json");
+        assert_eq!(config.runner.bench_budget, Duration::from_secs(2));
+        assert_eq!(config.runner.min_samples, 10);
+        assert!(!config.runner.color);
+        assert_eq!(config.client.timeout, Duration::from_secs(5));
+        assert_eq!(
+            config.client.contact.as_deref(),
+            Some("github.com/someone/advent-of-code")
+        );
+        assert!(config.input.normalize_line_endings);
+        assert!(config.input.trim_trailing_newline);
+        assert_eq!(config.days.get(&year!(2016)), Some(&3));
+        assert_eq!(config.days.get(&year!(2025)), None);
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn reports_invalid_configs() {
+        let error = |s: &str| Config::parse(s).unwrap_err().to_string();
+
+        assert_eq!(
+            error("year = 2014"),
+            "line 1: expected `year` to be a year of 2015 or later."
+        );
+        assert_eq!(
+            error("\n[runner]\nbudget = 1"),
+            "line 3: unknown key `runner.budget`."
+        );
+        assert_eq!(error("[other]"), "line 1: unknown table `[other]`.");
+        assert_eq!(
+            error("[days]\n2014 = 25"),
+            "line 2: unknown key `days.2014`."
+        );
+        for days in ["0", "26", "\"many\""] {
+            assert_eq!(
+                error(&format!("[days]\n2025 = {days}")),
+                "line 2: expected `2025` to be a number of days from 1 to 25."
+            );
+        }
+        assert_eq!(
+            error("[paths\n"),
+            "line 1: expected `]` after the table name."
+        );
+        assert_eq!(
+            error("[paths]\ndata = \"data"),
+            "line 2: could not read the value of `data`."
+        );
+        assert_eq!(
+            error("[runner]\ncolor = 1"),
+            "line 2: expected `color` to be `true` or `false`."
+        );
+        assert!(matches!(
+            Config::parse("[runner]\nmin_samples = 20\nmax_samples = 10"),
+            Err(ConfigError::Conflict(_))
+        ));
+    }
+}
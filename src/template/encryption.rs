@@ -0,0 +1,211 @@
+//! Module that encrypts inputs and puzzle descriptions, so that they can be committed.
+//!
+//! An encrypted file lives next to its plain text, e.g. `data/2024/inputs/01.txt.enc`.
+//! Its nonce is derived from the key and the plain text, so encrypting the same file twice gives the same result
+//! and does not show up as a change in git.
+//!
+//! The key is derived from a secret with Argon2. Since encrypted files are public, the secret has to be random and
+//! long enough that it cannot be guessed, e.g. the output of `openssl rand -hex 32`. Shorter secrets are rejected.
+
+use std::{env, error::Error, fmt::Display, fs, io, path::PathBuf};
+
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::template::config::project_root;
+
+/// Environment variable that holds the key.
+pub const KEY_ENV_VAR: &str = "AOC_INPUTS_KEY";
+
+/// File in the root of the project that holds the key, if the environment variable is not set.
+pub const KEY_FILE_NAME: &str = ".aoc-inputs-key";
+
+/// Extension appended to the path of an encrypted file.
+pub const ENCRYPTED_EXTENSION: &str = ".enc";
+
+/// Identifies encrypted files and the version of their format.
+const MAGIC: &[u8] = b"AOCENC1\n";
+
+const NONCE_LEN: usize = 12;
+
+/// The fewest characters of a secret, e.g. 16 random bytes in hex.
+const MIN_SECRET_LEN: usize = 32;
+
+/// Salt of the key derivation. It is fixed, so that every clone derives the same key from a secret.
+const SALT: &[u8] = b"advent_of_code inputs";
+
+/// A key, derived from the secret in [`KEY_ENV_VAR`] or [`KEY_FILE_NAME`].
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn new(secret: &str) -> Result<Self, EncryptionError> {
+        let secret = secret.trim();
+        if secret.chars().count() < MIN_SECRET_LEN {
+            return Err(EncryptionError::WeakKey);
+        }
+
+        let mut key = [0; 32];
+        Argon2::default()
+            .hash_password_into(secret.as_bytes(), SALT, &mut key)
+            .map_err(|_| EncryptionError::WeakKey)?;
+        Ok(Key(key))
+    }
+
+    /// Loads the key from [`KEY_ENV_VAR`], or from [`KEY_FILE_NAME`] in the root of the project.
+    pub fn load() -> Result<Self, EncryptionError> {
+        if let Some(secret) = env::var(KEY_ENV_VAR).ok().filter(|x| !x.trim().is_empty()) {
+            return Key::new(&secret);
+        }
+
+        match fs::read_to_string(key_file_path()) {
+            Ok(secret) if !secret.trim().is_empty() => Key::new(&secret),
+            Ok(_) => Err(EncryptionError::MissingKey),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(EncryptionError::MissingKey),
+            Err(e) => Err(EncryptionError::IO(e)),
+        }
+    }
+
+    pub fn encrypt(&self, plain: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.0);
+        hasher.update(plain);
+        let digest = hasher.finalize();
+        let nonce = Nonce::from_slice(&digest[..NONCE_LEN]);
+
+        let cipher = ChaCha20Poly1305::new(&self.0.into());
+        // only fails for plain texts of several gigabytes.
+        let encrypted = cipher.encrypt(nonce, plain).expect("could not encrypt");
+
+        [MAGIC, nonce.as_slice(), &encrypted].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|x| x.len() > NONCE_LEN)
+            .ok_or(EncryptionError::InvalidFormat)?;
+        let (nonce, encrypted) = data.split_at(NONCE_LEN);
+
+        ChaCha20Poly1305::new(&self.0.into())
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| EncryptionError::WrongKey)
+    }
+}
+
+pub fn key_file_path() -> PathBuf {
+    project_root().join(KEY_FILE_NAME)
+}
+
+/// Returns the path of the encrypted version of a file.
+pub fn encrypted_path(path: &str) -> String {
+    format!("{path}{ENCRYPTED_EXTENSION}")
+}
+
+/// Reads a text file. If it does not exist, its encrypted version is decrypted instead.
+pub fn read_to_string(path: &str) -> Result<String, EncryptionError> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let data = match fs::read(encrypted_path(path)) {
+                Ok(data) => data,
+                // report the plain file, which is what is usually missing.
+                Err(_) => return Err(EncryptionError::IO(e)),
+            };
+            let plain = Key::load()?.decrypt(&data)?;
+            String::from_utf8(plain).map_err(|_| EncryptionError::InvalidFormat)
+        }
+        result => result.map_err(EncryptionError::IO),
+    }
+}
+
+/// Whether a file or its encrypted version exists.
+pub fn exists(path: &str) -> bool {
+    fs::metadata(path).is_ok() || fs::metadata(encrypted_path(path)).is_ok()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    MissingKey,
+    WeakKey,
+    InvalidFormat,
+    WrongKey,
+    IO(io::Error),
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionError::MissingKey => write!(
+                f,
+                "no key to decrypt inputs. Set `{KEY_ENV_VAR}` or write the key to `{KEY_FILE_NAME}`"
+            ),
+            EncryptionError::WeakKey => write!(
+                f,
+                "the key is too short to be safe. Use at least {MIN_SECRET_LEN} random characters, e.g. from `openssl rand -hex 32`"
+            ),
+            EncryptionError::InvalidFormat => write!(f, "not an encrypted file"),
+            EncryptionError::WrongKey => write!(f, "the key does not match the file"),
+            EncryptionError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for EncryptionError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{EncryptionError, Key};
+
+    const SECRET: &str = "5f2b8e0c9d4a7b1e3c6f8a0d2b4e6c8a";
+    const OTHER: &str = "a8c6e4b2d0a8f6c3e1b7a4d9c0e8b2f5";
+
+    #[test]
+    fn encrypts_and_decrypts() {
+        let key = Key::new(&format!("{SECRET}\n")).unwrap();
+        let encrypted = key.encrypt(b"1 2 3\n");
+
+        assert!(encrypted.starts_with(b"AOCENC1\n"));
+        assert!(!encrypted.windows(5).any(|x| x == b"1 2 3"));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"1 2 3\n");
+
+        // encrypting again does not change the file.
+        assert_eq!(Key::new(SECRET).unwrap().encrypt(b"1 2 3\n"), encrypted);
+        assert_ne!(key.encrypt(b"1 2 4\n"), encrypted);
+    }
+
+    #[test]
+    fn rejects_other_keys_and_files() {
+        let key = Key::new(SECRET).unwrap();
+        let encrypted = key.encrypt(b"input");
+
+        assert!(matches!(
+            Key::new(OTHER).unwrap().decrypt(&encrypted),
+            Err(EncryptionError::WrongKey)
+        ));
+        assert!(matches!(
+            key.decrypt(b"input"),
+            Err(EncryptionError::InvalidFormat)
+        ));
+
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            key.decrypt(&tampered),
+            Err(EncryptionError::WrongKey)
+        ));
+    }
+
+    #[test]
+    fn rejects_short_secrets() {
+        assert!(matches!(Key::new("secret"), Err(EncryptionError::WeakKey)));
+        assert!(matches!(
+            Key::new(&format!("  {}  ", &SECRET[1..])),
+            Err(EncryptionError::WeakKey)
+        ));
+    }
+}
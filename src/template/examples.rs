@@ -0,0 +1,184 @@
+//! Module that extracts example inputs and their expected answers from puzzle descriptions.
+
+use crate::template::aoc_client::PART_TWO_HEADING;
+use crate::template::Part;
+
+/// Everything that might be the example of a puzzle part, in order of appearance.
+#[derive(Debug, Default, PartialEq)]
+pub struct Candidates {
+    /// The contents of code blocks.
+    pub inputs: Vec<String>,
+    /// Highlighted values, i.e. `<code><em>` in the puzzle.
+    pub answers: Vec<String>,
+}
+
+/// Finds the candidates of both parts in a puzzle description written by `cargo download`.
+pub fn find_candidates(markdown: &str) -> (Candidates, Candidates) {
+    match markdown.split_once(PART_TWO_HEADING) {
+        Some((part_1, part_2)) => (parse_section(part_1), parse_section(part_2)),
+        None => (parse_section(markdown), Candidates::default()),
+    }
+}
+
+fn parse_section(section: &str) -> Candidates {
+    let mut candidates = Candidates::default();
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in section.lines() {
+        if line == "```" {
+            match block.take() {
+                Some(lines) => candidates.inputs.push(lines.join("\n")),
+                None => block = Some(vec![]),
+            }
+            continue;
+        }
+
+        match &mut block {
+            Some(lines) => lines.push(line),
+            None => candidates.answers.extend(find_highlights(line)),
+        }
+    }
+
+    candidates
+}
+
+/// Finds values that are both code and emphasized, e.g. `` `*42*` `` or `` *`42`* ``.
+fn find_highlights(line: &str) -> Vec<String> {
+    let mut highlights = vec![];
+    let mut rest = line;
+
+    while let Some(start) = [rest.find("`*"), rest.find("*`")]
+        .into_iter()
+        .flatten()
+        .min()
+    {
+        let close = if rest[start..].starts_with("`*") {
+            "*`"
+        } else {
+            "`*"
+        };
+
+        let content = &rest[start + 2..];
+        let Some(end) = content.find(close) else {
+            break;
+        };
+
+        let value = &content[..end];
+        if !value.is_empty() && !value.contains(['`', '*']) {
+            highlights.push(value.to_string());
+        }
+
+        rest = &content[end + 2..];
+    }
+
+    highlights
+}
+
+/// Replaces the `None` assertion in the scaffolded test of a part with the expected answer.
+/// If `example_part` is set, the test reads the example file of that part instead, e.g. `01-2.txt`.
+/// Returns `None` if the test does not exist or its assertion was already changed.
+pub fn fill_test(
+    module: &str,
+    part: Part,
+    answer: &str,
+    example_part: Option<Part>,
+) -> Option<String> {
+    let name = match part {
+        Part::One => "fn test_part_one()",
+        Part::Two => "fn test_part_two()",
+    };
+
+    let start = module.find(name)?;
+    let end = module[start..]
+        .find("#[test]")
+        .map_or(module.len(), |x| start + x);
+
+    let test = &module[start..end];
+    if !test.contains("assert_eq!(result, None);") {
+        return None;
+    }
+
+    let expected = if answer.parse::<i64>().is_ok() {
+        format!("Some({answer})")
+    } else {
+        format!("Some(String::from({answer:?}))")
+    };
+
+    let mut test = test.replacen(
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, {expected});"),
+        1,
+    );
+
+    if let Some(example_part) = example_part {
+        let example_part = match example_part {
+            Part::One => "advent_of_code::template::Part::One",
+            Part::Two => "advent_of_code::template::Part::Two",
+        };
+        test = test.replacen(
+            "read_file(\"examples\", PUZZLE)",
+            &format!("read_file_part(\"examples\", PUZZLE, {example_part})"),
+            1,
+        );
+    }
+
+    Some(format!("{}{test}{}", &module[..start], &module[end..]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fill_test, find_candidates, find_highlights};
+    use crate::template::Part;
+
+    static TEMPLATE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+    #[test]
+    fn finds_candidates() {
+        let puzzle = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "The smallest pair is `*1*`, the total distance is `*11*`.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "The similarity score is *`31`*.",
+        ]
+        .join("\n");
+
+        let (part_1, part_2) = find_candidates(&puzzle);
+        assert_eq!(part_1.inputs, vec!["3   4\n4   3"]);
+        assert_eq!(part_1.answers, vec!["1", "11"]);
+        assert!(part_2.inputs.is_empty());
+        assert_eq!(part_2.answers, vec!["31"]);
+    }
+
+    #[test]
+    fn ignores_plain_emphasis() {
+        assert!(find_highlights("the *total* of `3   4`").is_empty());
+        assert_eq!(find_highlights("`*XMAS*` and `*-3*`"), vec!["XMAS", "-3"]);
+    }
+
+    #[test]
+    fn fills_tests() {
+        let module = fill_test(TEMPLATE, Part::Two, "31", Some(Part::Two)).unwrap();
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(module.contains("assert_eq!(result, Some(31));"));
+        assert!(module
+            .contains("read_file_part(\"examples\", PUZZLE, advent_of_code::template::Part::Two)"));
+
+        let module = fill_test(&module, Part::One, "abc", None).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(String::from(\"abc\")));"));
+        assert_eq!(module.matches("read_file(\"examples\", PUZZLE)").count(), 1);
+
+        assert_eq!(fill_test(&module, Part::One, "12", None), None);
+    }
+}
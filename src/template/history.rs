@@ -0,0 +1,303 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::timings::Timings;
+use crate::template::{config, create_data_file, get_data_path, Day, Part, Year};
+
+static SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Represents every recorded benchmark time of a single day, oldest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayHistory {
+    pub day: Day,
+    pub part_1: Vec<f64>,
+    pub part_2: Vec<f64>,
+}
+
+/// Represents the benchmark history of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub data: Vec<DayHistory>,
+}
+
+impl History {
+    /// Dehydrate the history to the history file of a benchmark table.
+    pub fn store_file(&self, year: Year, table: Option<&str>) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = create_data_file(&get_history_file_path(year, table))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history of a benchmark table. If not present, returns an empty history.
+    pub fn read_from_file(year: Year, table: Option<&str>) -> Self {
+        fs::read_to_string(get_history_file_path(year, table))
+            .map_err(|x| x.to_string())
+            .and_then(History::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Append the timings of a benchmark run.
+    pub fn record(&mut self, timings: &Timings) {
+        for timing in &timings.data {
+            let index = match self.data.iter().position(|h| h.day == timing.day) {
+                Some(index) => index,
+                None => {
+                    self.data.push(DayHistory {
+                        day: timing.day,
+                        part_1: vec![],
+                        part_2: vec![],
+                    });
+                    self.data.len() - 1
+                }
+            };
+
+            let history = &mut self.data[index];
+
+            if let Some(part) = &timing.part_1 {
+                history.part_1.push(part.nanos);
+            }
+
+            if let Some(part) = &timing.part_2 {
+                history.part_2.push(part.nanos);
+            }
+        }
+
+        self.data.sort_unstable_by_key(|h| h.day);
+    }
+
+    /// Returns all recorded timings of a part, oldest first.
+    pub fn get(&self, day: Day, part: Part) -> &[f64] {
+        let Some(history) = self.data.iter().find(|h| h.day == day) else {
+            return &[];
+        };
+
+        match part {
+            Part::One => &history.part_1,
+            Part::Two => &history.part_2,
+        }
+    }
+
+    /// Formats the trend of a part as a sparkline of its last `n` timings, followed by its best timing.
+    /// Returns `None` if fewer than two timings were recorded.
+    pub fn format_trend(&self, day: Day, part: Part, n: usize) -> Option<String> {
+        let values = self.get(day, part);
+        let best = values.iter().copied().reduce(f64::min)?;
+        let window = &values[values.len().saturating_sub(n)..];
+
+        Some(format!(
+            "{} (best {})",
+            sparkline(window)?,
+            format_nanos(best)
+        ))
+    }
+}
+
+/// Renders values as a sparkline, scaled between their minimum and maximum.
+fn sparkline(values: &[f64]) -> Option<String> {
+    if values.len() < 2 {
+        return None;
+    }
+
+    let min = values.iter().copied().reduce(f64::min)?;
+    let max = values.iter().copied().reduce(f64::max)?;
+    let range = max - min;
+
+    Some(
+        values
+            .iter()
+            .map(|x| {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = if range > 0.0 {
+                    ((x - min) / range * (SPARK_CHARS.len() - 1) as f64).round() as usize
+                } else {
+                    0
+                };
+                SPARK_CHARS[index]
+            })
+            .collect(),
+    )
+}
+
+/// Formats nanoseconds the same way the runner prints durations.
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+/// The history of a benchmark table lives next to its timings, e.g. `data/2024/timings.history.json`,
+/// or `data/2024/timings-laptop.history.json` for the table `laptop`.
+fn get_history_file_path(year: Year, table: Option<&str>) -> String {
+    // the file name is checked to end with `.json` when reading the config.
+    let stem = config().paths.timings.trim_end_matches(".json");
+    let file_name = match table {
+        Some(name) => format!("{stem}-{name}.history.json"),
+        None => format!("{stem}.history.json"),
+    };
+    get_data_path(year, &file_name)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(History {
+            data: json_data
+                .iter()
+                .map(DayHistory::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayHistory> for JsonValue {
+    fn from(value: &DayHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                JsonValue::Array(part.iter().map(|x| JsonValue::Number(*x)).collect()),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayHistory {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected history.day to be a Day struct.")?;
+
+        let parse_part = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<Vec<JsonValue>>())
+                .and_then(|v| {
+                    v.iter()
+                        .map(|x| x.get::<f64>().copied())
+                        .collect::<Option<Vec<_>>>()
+                })
+                .ok_or(format!("Expected history.{key} to be an array of numbers."))
+        };
+
+        Ok(DayHistory {
+            day,
+            part_1: parse_part("part_1")?,
+            part_2: parse_part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_history_file_path, sparkline, History};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+        template::Part,
+        year,
+    };
+
+    fn get_run(part_1: &str, part_2: Option<&str>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some(part_1.into()),
+                part_2: part_2.map(Into::into),
+                total_nanos: 0_f64,
+            }],
+        }
+    }
+
+    #[test]
+    fn names_history_files_after_timings() {
+        assert!(get_history_file_path(year!(2024), None).ends_with("2024/timings.history.json"));
+        assert!(get_history_file_path(year!(2024), Some("json-laptop"))
+            .ends_with("2024/timings-json-laptop.history.json"));
+    }
+
+    #[test]
+    fn records_runs() {
+        let mut history = History::default();
+        history.record(&get_run("10ms", Some("1ms")));
+        history.record(&get_run("20ms", None));
+        assert_eq!(
+            history.get(day!(1), Part::One),
+            &[10_000_000_f64, 20_000_000_f64]
+        );
+        assert_eq!(history.get(day!(1), Part::Two), &[1_000_000_f64]);
+        assert!(history.get(day!(2), Part::One).is_empty());
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut history = History::default();
+        history.record(&get_run("10ms", Some("1ms")));
+        let json = tinyjson::JsonValue::from(history.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(History::try_from(json).unwrap().data, history.data);
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5]).unwrap(), "▁█▅");
+        assert_eq!(sparkline(&[3.0, 3.0]).unwrap(), "▁▁");
+        assert_eq!(sparkline(&[3.0]), None);
+    }
+
+    #[test]
+    fn formats_trends() {
+        let mut history = History::default();
+        for time in ["1ms", "3ms", "2ms", "4ms"] {
+            history.record(&get_run(time, None));
+        }
+        assert_eq!(
+            history.format_trend(day!(1), Part::One, 3).unwrap(),
+            "▅▁█ (best 1.0ms)"
+        );
+        assert_eq!(history.format_trend(day!(1), Part::Two, 3), None);
+    }
+}
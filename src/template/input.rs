@@ -0,0 +1,124 @@
+//! Module that checks and normalizes the text files that `read_file` hands to solutions.
+
+use std::{error::Error, fmt::Display};
+
+use crate::template::config::Input;
+
+/// Byte order mark that some editors on Windows put at the start of a file.
+const BOM: char = '\u{feff}';
+
+/// Texts of the website that show up in place of an input, e.g. when the session expired.
+const ERROR_PAGE_MARKERS: [&str; 2] = [
+    "Please log in to get your puzzle input",
+    "Please don't repeatedly request this endpoint before it unlocks",
+];
+
+/// Applies the input policies of `aoc.toml` to the contents of a file. Byte order marks are always removed.
+pub fn normalize(contents: &str, policy: &Input) -> String {
+    let mut contents = contents.strip_prefix(BOM).unwrap_or(contents).to_string();
+
+    if policy.normalize_line_endings {
+        contents = contents.replace("\r\n", "\n");
+    }
+
+    if policy.trim_trailing_newline {
+        let len = contents.trim_end_matches(['\r', '\n']).len();
+        contents.truncate(len);
+    }
+
+    contents
+}
+
+/// Checks that the contents of a file look like a puzzle input.
+pub fn validate(contents: &str) -> Result<(), InputError> {
+    let trimmed = contents.trim_start();
+
+    if trimmed.is_empty() {
+        return Err(InputError::Empty);
+    }
+
+    let start = trimmed
+        .chars()
+        .take(20)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if start.starts_with("<!doctype html")
+        || start.starts_with("<html")
+        || ERROR_PAGE_MARKERS.iter().any(|x| trimmed.contains(x))
+    {
+        return Err(InputError::ErrorPage);
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, PartialEq)]
+pub enum InputError {
+    Empty,
+    ErrorPage,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Empty => write!(f, "the file is empty"),
+            InputError::ErrorPage => write!(
+                f,
+                "the file contains an error page of the website instead of an input. Check your session with `cargo session check`"
+            ),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{normalize, validate, InputError};
+    use crate::template::config::Input;
+
+    #[test]
+    fn normalizes_inputs() {
+        let keep = Input {
+            normalize_line_endings: false,
+            trim_trailing_newline: false,
+        };
+        let normalize_all = Input {
+            normalize_line_endings: true,
+            trim_trailing_newline: true,
+        };
+
+        let input = "\u{feff}3   4\r\n4   3\r\n\r\n";
+        assert_eq!(normalize(input, &keep), "3   4\r\n4   3\r\n\r\n");
+        assert_eq!(normalize(input, &normalize_all), "3   4\n4   3");
+        assert_eq!(
+            normalize(
+                input,
+                &Input {
+                    trim_trailing_newline: false,
+                    ..normalize_all
+                }
+            ),
+            "3   4\n4   3\n\n"
+        );
+    }
+
+    #[test]
+    fn validates_inputs() {
+        assert_eq!(validate("3   4\n"), Ok(()));
+        assert_eq!(validate(""), Err(InputError::Empty));
+        assert_eq!(validate(" \n\n"), Err(InputError::Empty));
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err(InputError::ErrorPage)
+        );
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(InputError::ErrorPage)
+        );
+    }
+}
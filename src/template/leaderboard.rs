@@ -0,0 +1,324 @@
+//! Module that renders private leaderboards of the Advent of Code website.
+use std::{cmp::Reverse, collections::HashMap, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{all_days, Day, Year};
+
+/// Marks the leaderboard section of the readme, like the benchmark tables.
+pub static MARKER: &str = "<!--- leaderboard --->";
+
+/// Puzzles unlock at midnight UTC-5.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Unix timestamps at which a member earned the stars of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Completion {
+    pub day: Day,
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    pub completions: Vec<Completion>,
+}
+
+impl Member {
+    fn get_completion(&self, day: Day) -> Option<&Completion> {
+        self.completions.iter().find(|c| c.day == day)
+    }
+}
+
+/// A private leaderboard, with members ordered by their rank.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leaderboard {
+    pub year: Year,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Renders the standings and the star times of every day for the terminal.
+    pub fn format_terminal(&self) -> String {
+        let name_width = self
+            .members
+            .iter()
+            .map(|m| m.name.chars().count())
+            .max()
+            .unwrap_or_default()
+            .max(4);
+
+        let mut lines = vec![format!("{:>3}  {:>5}  {:>5}  Name", "#", "Score", "Stars")];
+
+        for (rank, member) in self.members.iter().enumerate() {
+            lines.push(format!(
+                "{:>3}  {:>5}  {:>5}  {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.name
+            ));
+        }
+
+        for day in all_days(self.year) {
+            let mut completions: Vec<(&Member, &Completion)> = self
+                .members
+                .iter()
+                .filter_map(|m| Some((m, m.get_completion(day)?)))
+                .collect();
+
+            if completions.is_empty() {
+                continue;
+            }
+
+            // finishers first, each group ordered by time.
+            completions
+                .sort_by_key(|(_, c)| (c.part_2.unwrap_or(u64::MAX), c.part_1.unwrap_or(u64::MAX)));
+
+            let unlock = get_unlock_ts(self.year, day);
+
+            lines.push(String::new());
+            lines.push(format!(
+                "Day {day}  {:name_width$}  {:>10}  {:>10}  {:>10}",
+                "", "Part 1", "Part 2", "Delta"
+            ));
+
+            for (member, completion) in completions {
+                let format_time = |ts: Option<u64>| {
+                    ts.map_or_else(|| "-".into(), |x| format_duration(x.saturating_sub(unlock)))
+                };
+
+                let delta = match (completion.part_1, completion.part_2) {
+                    (Some(part_1), Some(part_2)) => {
+                        format!("+{}", format_duration(part_2.saturating_sub(part_1)))
+                    }
+                    _ => "-".into(),
+                };
+
+                lines.push(format!(
+                    "        {:name_width$}  {:>10}  {:>10}  {:>10}",
+                    member.name,
+                    format_time(completion.part_1),
+                    format_time(completion.part_2),
+                    delta
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Renders the standings as markdown, without the surrounding markers.
+    pub fn format_markdown(&self) -> String {
+        let mut lines: Vec<String> = vec![
+            format!("## Leaderboard {}", self.year),
+            String::new(),
+            "| # | Name | Score | Stars |".into(),
+            "| :---: | --- | :---: | :---: |".into(),
+        ];
+
+        for (rank, member) in self.members.iter().enumerate() {
+            lines.push(format!(
+                "| {} | {} | {} | {} |",
+                rank + 1,
+                member.name.replace('|', "\\|"),
+                member.local_score,
+                member.stars
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Returns the unix timestamp at which the puzzle of a day unlocked.
+fn get_unlock_ts(year: Year, day: Day) -> u64 {
+    // see: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = u64::from(year.into_inner());
+    let era = year / 400;
+    let yoe = year - era * 400;
+    // december is the 10th month of a year that starts in march.
+    let doy = (153 * 9 + 2) / 5 + u64::from(day.into_inner()) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    days * 86_400 + UNLOCK_HOUR_UTC * 3600
+}
+
+/// Formats a duration in seconds as `HH:MM:SS`. Hours are not wrapped into days.
+fn format_duration(secs: u64) -> String {
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&JsonValue> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|x| x.parse().ok())
+            .ok_or("expected `json.event` to be a year.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // same order as on the website: score, then the earliest last star.
+        members.sort_by_key(|m| (Reverse(m.local_score), m.last_star_ts, m.id));
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let get_number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+                .ok_or(format!("Expected member.{key} to be a number."))
+        };
+
+        let id = get_number("id")?;
+
+        // anonymous users have no name.
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let mut completions = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?
+            .iter()
+            .map(|(day, parts)| {
+                let day = Day::from_str(day).map_err(|e| e.to_string())?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion to be a JSON object.")?;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let get_ts = |part: &str| {
+                    parts
+                        .get(part)?
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get("get_star_ts")?
+                        .get::<f64>()
+                        .map(|x| *x as u64)
+                };
+
+                Ok(Completion {
+                    day,
+                    part_1: get_ts("1"),
+                    part_2: get_ts("2"),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        completions.sort_unstable_by_key(|c| c.day);
+
+        Ok(Member {
+            id,
+            name,
+            local_score: get_number("local_score")?,
+            stars: get_number("stars")?,
+            last_star_ts: get_number("last_star_ts")?,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_duration, get_unlock_ts, Leaderboard};
+    use crate::{day, year};
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    fn get_mock_leaderboard() -> Leaderboard {
+        let json = r#"{
+            "event": "2024",
+            "owner_id": 1,
+            "members": {
+                "1": {
+                    "id": 1, "name": "Alice", "local_score": 4, "stars": 2, "global_score": 0,
+                    "last_star_ts": 1733030100,
+                    "completion_day_level": {
+                        "1": {
+                            "1": { "get_star_ts": 1733029500, "star_index": 1 },
+                            "2": { "get_star_ts": 1733030100, "star_index": 2 }
+                        }
+                    }
+                },
+                "2": {
+                    "id": 2, "name": null, "local_score": 4, "stars": 1, "global_score": 0,
+                    "last_star_ts": 1733029400,
+                    "completion_day_level": {
+                        "1": { "1": { "get_star_ts": 1733029400, "star_index": 0 } }
+                    }
+                }
+            }
+        }"#;
+
+        Leaderboard::try_from(&JsonValue::from_str(json).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = get_mock_leaderboard();
+        assert_eq!(leaderboard.year, year!(2024));
+        // equal scores are ordered by the earliest last star.
+        assert_eq!(leaderboard.members[0].name, "(anonymous user #2)");
+        assert_eq!(
+            leaderboard.members[1].completions[0].part_2,
+            Some(1733030100)
+        );
+    }
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(get_unlock_ts(year!(2024), day!(1)), 1733029200);
+        assert_eq!(get_unlock_ts(year!(2023), day!(25)), 1703480400);
+    }
+
+    #[test]
+    fn formats_terminal_output() {
+        let output = get_mock_leaderboard().format_terminal();
+        assert!(output.contains("  1      4      1  (anonymous user #2)"));
+        assert!(output.contains("00:05:00    00:15:00   +00:10:00"));
+        assert_eq!(format_duration(90_061), "25:01:01");
+    }
+
+    #[test]
+    fn formats_markdown() {
+        let markdown = get_mock_leaderboard().format_markdown();
+        assert!(markdown.starts_with("## Leaderboard 2024"));
+        assert!(markdown.ends_with("| 2 | Alice | 4 | 2 |"));
+    }
+}
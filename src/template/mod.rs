@@ -1,59 +1,164 @@
-use std::{env, fs};
+use std::{fs, io, path::Path};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod config;
 pub mod runner;
 
+pub use config::{config, data_dir, Config};
 pub use day::*;
+pub use part::*;
+pub use puzzle::*;
+pub use report::ReportFormat;
+pub use timings::MergePolicy;
 
+mod answers;
+mod archive;
 mod day;
+mod encryption;
+mod examples;
+mod history;
+mod input;
+mod leaderboard;
+mod part;
+mod puzzle;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
+mod status;
+mod submissions;
 mod timings;
+mod year;
+
+pub use year::*;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2024/inputs/01.txt`.
+/// The data folder is found independently of the working directory, see [`data_dir`].
+/// If only an encrypted version of the file exists, e.g. `01.txt.enc`, it is decrypted.
+/// With the `embed_inputs` feature, the copy that was compiled into the binary is used if there is one.
+///
+/// Byte order marks are removed and the policies of `[input]` in `aoc.toml` are applied, e.g. to normalize line endings.
+/// Empty inputs and error pages of the website fail with a message that explains how to fix them.
+/// Other files may be empty, e.g. the example files that `cargo scaffold` creates.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    read_data_file(folder, puzzle, &format!("{}.txt", puzzle.day))
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file of a puzzle to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: Part) -> String {
+    read_data_file(folder, puzzle, &format!("{}-{part}.txt", puzzle.day))
+}
+
+fn read_data_file(folder: &str, puzzle: PuzzleId, file_name: &str) -> String {
+    #[cfg(feature = "embed_inputs")]
+    if let Some(contents) = embedded::get(puzzle.year, folder, file_name) {
+        let source = format!("the embedded \"{}/{folder}/{file_name}\"", puzzle.year);
+        return prepare_input(contents, &source, folder, puzzle);
+    }
+
+    let path = get_data_path(puzzle.year, &format!("{folder}/{file_name}"));
+
+    let contents = match encryption::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(encryption::EncryptionError::IO(e)) => {
+            panic!(
+                "could not open \"{path}\": {e}. {}",
+                get_hint(folder, puzzle)
+            );
+        }
+        Err(e) => panic!(
+            "could not decrypt \"{}\": {e}.",
+            encryption::encrypted_path(&path)
+        ),
+    };
+
+    prepare_input(&contents, &format!("\"{path}\""), folder, puzzle)
+}
+
+/// Inputs and examples of the data folder that the build script compiled into the binary.
+#[cfg(feature = "embed_inputs")]
+mod embedded {
+    use crate::template::Year;
+
+    include!(concat!(env!("OUT_DIR"), "/embedded_data.rs"));
+
+    pub fn get(year: Year, folder: &str, file_name: &str) -> Option<&'static str> {
+        let path = format!("{year}/{folder}/{file_name}");
+        FILES
+            .iter()
+            .find(|(x, _)| *x == path)
+            .map(|(_, contents)| *contents)
+    }
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Normalizes and validates the contents of a data file, see [`read_file`].
+fn prepare_input(contents: &str, source: &str, folder: &str, puzzle: PuzzleId) -> String {
+    let contents = input::normalize(contents, &config().input);
+
+    match input::validate(&contents) {
+        Err(input::InputError::Empty) if folder != "inputs" => {}
+        Err(e) => panic!("could not use {source}: {e}. {}", get_hint(folder, puzzle)),
+        Ok(()) => {}
+    }
+
+    contents
+}
+
+fn get_hint(folder: &str, puzzle: PuzzleId) -> String {
+    match folder {
+        "inputs" => format!("Run `cargo download {puzzle}` to download it."),
+        "examples" => format!("Run `cargo examples {puzzle}` or add it by hand."),
+        _ => format!(
+            "Set `{}` to use another data folder.",
+            config::DATA_DIR_ENV_VAR
+        ),
+    }
+}
+
+/// Returns the path of a file in the data folder of a year, e.g. `data/2024/answers.json`.
+pub(crate) fn get_data_path(year: Year, file: &str) -> String {
+    data_dir()
+        .join(year.to_string())
+        .join(file)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Creates a file in the data folder, creating the folder of its year first if needed.
+pub(crate) fn create_data_file(path: &str) -> Result<fs::File, io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::File::create(path)
+}
+
+/// Creates the constant `PUZZLE` from a year and a day and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, One] [part_two, Two]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, One]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, Two]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:ident] )*) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId {
+            year: $crate::year!($year),
+            day: $crate::day!($day),
+        };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +166,43 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let args = RunnerArgs::from_env();
+            let input = $crate::template::read_file("inputs", PUZZLE);
+            $( run_part($func, &input, PUZZLE, $crate::template::Part::$part, &args); )*
         }
     };
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{prepare_input, read_file, PuzzleId};
+    use crate::{day, year};
+
+    const PUZZLE: PuzzleId = PuzzleId {
+        year: year!(2024),
+        day: day!(1),
+    };
+
+    #[test]
+    fn reads_files_of_the_data_folder() {
+        let example = read_file("examples", PUZZLE);
+        assert!(example.starts_with("3   4\n"));
+    }
+
+    #[test]
+    fn allows_empty_examples() {
+        assert_eq!(prepare_input("", "\"01.txt\"", "examples", PUZZLE), "");
+        assert_eq!(
+            prepare_input("\u{feff}", "\"01.txt\"", "examples", PUZZLE),
+            ""
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "could not use \"01.txt\": the file is empty")]
+    fn rejects_empty_inputs() {
+        let _ = prepare_input("\n", "\"01.txt\"", "inputs", PUZZLE);
+    }
+}
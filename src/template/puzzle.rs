@@ -0,0 +1,219 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+pub const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `year/day`, the format accepted by the commands.
+///
+/// ```
+/// # use advent_of_code::template::PuzzleId;
+/// let puzzle: PuzzleId = "2023/5".parse().unwrap();
+/// assert_eq!(puzzle.to_string(), "2023/05");
+/// assert_eq!(puzzle.bin_name(), "2023_05");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// The name of the solution binary, e.g. `2024_01` for `src/bin/2024_01.rs`.
+    pub fn bin_name(self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's a day of this year's event, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Day::new(u8::try_from(today.day()).ok()?)?;
+        year.has_day(day).then_some(Self::new(year, day))
+    }
+
+    /// Returns the next puzzle to unlock and its unlock time, i.e. the next midnight in the server's timezone
+    /// that falls on a day of an event.
+    pub fn next_unlock() -> Option<(Self, DateTime<Utc>)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        next_unlock_after(Utc::now().with_timezone(&offset))
+    }
+}
+
+#[cfg(feature = "today")]
+fn next_unlock_after(now: DateTime<FixedOffset>) -> Option<(PuzzleId, DateTime<Utc>)> {
+    let tomorrow = now.date_naive().succ_opt()?;
+    let days = Year::new(u16::try_from(tomorrow.year()).ok()?)?.days();
+
+    let date = if tomorrow.month() == 12 && tomorrow.day() <= u32::from(days) {
+        tomorrow
+    } else if now.month() == 12 {
+        NaiveDate::from_ymd_opt(now.year() + 1, 12, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(now.year(), 12, 1)?
+    };
+
+    let unlock = date
+        .and_hms_opt(0, 0, 0)?
+        .and_local_timezone(*now.offset())
+        .single()?;
+
+    let puzzle = PuzzleId::new(
+        Year::new(u16::try_from(date.year()).ok()?)?,
+        Day::new(u8::try_from(date.day()).ok()?)?,
+    );
+
+    Some((puzzle, unlock.with_timezone(&Utc)))
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    /// Parses `year/day`, e.g. `2023/5`. A day on its own belongs to the default year, see [`Year::configured`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_year(s, None)
+    }
+}
+
+impl PuzzleId {
+    /// Parses `year/day` like [`PuzzleId::from_str`], but a day on its own belongs to `year` if given,
+    /// e.g. the value of `--year`.
+    pub fn parse_with_year(s: &str, year: Option<Year>) -> Result<Self, PuzzleIdFromStrError> {
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (
+                year.parse().map_err(|_| PuzzleIdFromStrError::Invalid)?,
+                day,
+            ),
+            None => (
+                year.or_else(Year::configured)
+                    .ok_or(PuzzleIdFromStrError::Invalid)?,
+                s,
+            ),
+        };
+
+        let day = day.parse().map_err(|_| PuzzleIdFromStrError::Invalid)?;
+        if !year.has_day(day) {
+            return Err(PuzzleIdFromStrError::NotInYear(year));
+        }
+
+        Ok(Self::new(year, day))
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub enum PuzzleIdFromStrError {
+    Invalid,
+    NotInYear(Year),
+}
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleIdFromStrError::Invalid => f.write_str(
+                "expecting a day number, prefixed with a year like `2023/5` if no default year is configured",
+            ),
+            PuzzleIdFromStrError::NotInYear(year) => write!(
+                f,
+                "expecting a day number between 1 and {} for {year}",
+                year.days()
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn parses_puzzle_ids() {
+        let puzzle: PuzzleId = "2023/5".parse().unwrap();
+        assert_eq!(puzzle, PuzzleId::new(year!(2023), day!(5)));
+        assert_eq!(puzzle.to_string(), "2023/05");
+        assert_eq!(puzzle.bin_name(), "2023_05");
+
+        // the default year is set in `aoc.toml`.
+        let puzzle: PuzzleId = "05".parse().unwrap();
+        assert_eq!(puzzle, PuzzleId::new(year!(2024), day!(5)));
+
+        let puzzle = PuzzleId::parse_with_year("5", Some(year!(2023))).unwrap();
+        assert_eq!(puzzle, PuzzleId::new(year!(2023), day!(5)));
+        let puzzle = PuzzleId::parse_with_year("2022/5", Some(year!(2023))).unwrap();
+        assert_eq!(puzzle, PuzzleId::new(year!(2022), day!(5)));
+        assert!(PuzzleId::parse_with_year("13", Some(year!(2025))).is_err());
+
+        assert!("2014/1".parse::<PuzzleId>().is_err());
+        assert!("2023/26".parse::<PuzzleId>().is_err());
+        assert!("2023/".parse::<PuzzleId>().is_err());
+        assert!("2025/13".parse::<PuzzleId>().is_err());
+    }
+
+    #[cfg(feature = "today")]
+    #[test]
+    fn next_unlock() {
+        use super::next_unlock_after;
+        use chrono::DateTime;
+
+        let next = |now: &str| {
+            let (puzzle, unlock) =
+                next_unlock_after(DateTime::parse_from_rfc3339(now).unwrap()).unwrap();
+            (puzzle.to_string(), unlock.to_rfc3339())
+        };
+
+        assert_eq!(
+            next("2024-12-05T23:59:00-05:00"),
+            ("2024/06".into(), "2024-12-06T05:00:00+00:00".into())
+        );
+        assert_eq!(
+            next("2024-11-30T12:00:00-05:00"),
+            ("2024/01".into(), "2024-12-01T05:00:00+00:00".into())
+        );
+        assert_eq!(
+            next("2024-12-25T00:00:01-05:00"),
+            ("2025/01".into(), "2025-12-01T05:00:00+00:00".into())
+        );
+        assert_eq!(
+            next("2025-12-12T00:00:01-05:00"),
+            ("2026/01".into(), "2026-12-01T05:00:00+00:00".into())
+        );
+        assert_eq!(
+            next("2025-03-01T00:00:00-05:00"),
+            ("2025/01".into(), "2025-12-01T05:00:00+00:00".into())
+        );
+    }
+}
@@ -1,11 +1,31 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::answers::Answers;
+use crate::template::config::project_root;
+use crate::template::history::History;
+use crate::template::leaderboard;
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{config, create_data_file, get_data_path, Part, PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
+static NAMED_MARKER_START: &str = "<!--- benchmarking table: ";
+
+/// Marks the table of stars that the `aoc-readme-stars` action renders.
+static STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Marks the section that links the archives of previous years.
+static ARCHIVE_MARKER: &str = "<!--- archived years --->";
+
+static TEMPLATE_START: &str = "<!--- template: ";
+static TEMPLATE_END: &str = "<!--- end template --->";
+static MARKER_CLOSE: &str = " --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -26,37 +46,79 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
+}
+
+/// Returns the marker of a benchmark table.
+/// Named tables, e.g. `<!--- benchmarking table: laptop --->`, can be placed side by side with the default one.
+fn get_marker(table: Option<&str>) -> String {
+    match table {
+        Some(name) => format!("{NAMED_MARKER_START}{name}{MARKER_CLOSE}"),
+        None => MARKER.into(),
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Finds the section between two occurrences of `marker`. Examples in fenced code blocks are ignored.
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme
+        .match_indices(marker)
+        .filter(|(pos, _)| !is_in_code_block(readme, *pos))
+        .collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table start position.")))?;
 
     let pos_end = matches
         .last()
         .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table end position.")))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    table: Option<&str>,
+    timings: Timings,
+    total_millis: f64,
+    trend: &Trend,
+) -> String {
+    let marker = get_marker(table);
+    let content = format_table(prefix, year, table, timings, total_millis, trend);
+    [marker.as_str(), &content, marker.as_str()].join("\n")
+}
+
+/// The benchmark history shown next to each timing, limited to the last `len` runs.
+pub struct Trend<'a> {
+    pub history: &'a History,
+    pub len: usize,
+}
+
+/// Renders the benchmark table as markdown, without the surrounding markers.
+pub fn format_table(
+    prefix: &str,
+    year: Year,
+    table: Option<&str>,
+    timings: Timings,
+    total_millis: f64,
+    trend: &Trend,
+) -> String {
+    let header = match table {
+        Some(name) => format!("{prefix} Benchmarks ({name})"),
+        None => format!("{prefix} Benchmarks"),
+    };
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
@@ -64,43 +126,344 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(PuzzleId::new(year, timing.day));
+        let format_part = |part: Part, part_timing: Option<PartTiming>| {
+            let time = format!("`{}`", part_timing.map_or_else(|| "-".into(), |x| x.time));
+            match trend.history.format_trend(timing.day, part, trend.len) {
+                Some(trend) => format!("{time} {trend}"),
+                None => time,
+            }
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(Part::One, timing.part_1),
+            format_part(Part::Two, timing.part_2)
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    table: Option<&str>,
+    timings: Timings,
+    total_millis: f64,
+    trend: &Trend,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &get_marker(table))?;
+    let content = construct_table("##", year, table, timings, total_millis, trend);
+    s.replace_range(positions.pos_start..positions.pos_end, &content);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+/// Updates the benchmark table with the given name, or the default table if `table` is `None`, with the timings of a year.
+/// Template regions of the readme are re-rendered along with it.
+pub fn update(
+    year: Year,
+    table: Option<&str>,
+    timings: Timings,
+    trend: &Trend,
+) -> Result<(), Error> {
+    let path = project_root().join(&config().paths.readme);
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    update_content(&mut readme, year, table, timings, total_millis, trend)?;
+    update_templates(&mut readme, &TemplateContext::load())?;
+    fs::write(&path, &readme)?;
+    Ok(())
+}
+
+/// Replaces the section between two occurrences of `marker` with `content`.
+/// Like the benchmark tables, template regions of the readme are re-rendered along with it.
+pub fn update_section(marker: &str, content: &str) -> Result<(), Error> {
+    let path = project_root().join(&config().paths.readme);
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    let positions = locate_table(&readme, marker)?;
+    readme.replace_range(
+        positions.pos_start..positions.pos_end,
+        &[marker, content, marker].join("\n"),
+    );
+    update_templates(&mut readme, &TemplateContext::load())?;
+    fs::write(&path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Moves the sections that belong to a year, i.e. the table of stars, the benchmark tables and the leaderboard,
+/// from the readme into the archive of that year, e.g. `data/2024/README.md`. The empty markers stay in place.
+/// The archive is linked from the readme, and the template regions are re-rendered for the next year.
+/// Returns the path of the archive.
+pub fn archive(year: Year, next: Year) -> Result<String, Error> {
+    let path = project_root().join(&config().paths.readme);
+    let archive_path = get_data_path(year, "README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+
+    let sections = take_year_sections(&mut readme)?;
+    let mut content = vec![format!("# 🎄 Advent of Code {year}")];
+    // relative links of the readme have to climb up from the folder of the archive.
+    let link_path = format!("{}/{year}/README.md", config().paths.data);
+    let depth = Path::new(&link_path).parent().map_or(0, |x| {
+        x.components().filter(|x| x.as_os_str() != ".").count()
+    });
+    let root = "../".repeat(depth);
+    content.extend(
+        sections
+            .iter()
+            .map(|x| x.replace("](./", &format!("]({root}"))),
+    );
+
+    create_data_file(&archive_path)?.write_all((content.join("\n\n") + "\n").as_bytes())?;
+
+    link_archive(&mut readme, year, &link_path)?;
+    update_templates(&mut readme, &TemplateContext::load_year(Some(next)))?;
+    fs::write(&path, &readme)?;
+    Ok(archive_path)
+}
+
+/// Empties every section of the readme that belongs to a year and returns their contents, in order.
+fn take_year_sections(readme: &mut String) -> Result<Vec<String>, Error> {
+    let mut markers = vec![STARS_MARKER.to_string(), MARKER.to_string()];
+
+    for (pos, _) in readme.match_indices(NAMED_MARKER_START) {
+        if is_in_code_block(readme, pos) {
+            continue;
+        }
+
+        if let Some(len) = readme[pos..].find(MARKER_CLOSE) {
+            let marker = readme[pos..pos + len + MARKER_CLOSE.len()].to_string();
+            if !markers.contains(&marker) {
+                markers.push(marker);
+            }
+        }
+    }
+
+    markers.push(leaderboard::MARKER.into());
+
+    let mut sections = vec![];
+
+    for marker in markers {
+        if !contains_marker(readme, &marker) {
+            continue;
+        }
+
+        let positions = locate_table(readme, &marker)?;
+        if positions.pos_end - positions.pos_start < 2 * marker.len() {
+            return Err(Error::Parser(format!(
+                "{marker}: could not find section end position."
+            )));
+        }
+
+        let content = readme[positions.pos_start + marker.len()..positions.pos_end - marker.len()]
+            .trim()
+            .to_string();
+
+        readme.replace_range(
+            positions.pos_start..positions.pos_end,
+            &format!("{marker}\n{marker}"),
+        );
+
+        if !content.is_empty() {
+            sections.push(content);
+        }
+    }
+
+    Ok(sections)
+}
+
+/// Adds the archive of a year at `archive_path` to the list of previous years, newest first.
+/// Does nothing if the readme has no such section.
+fn link_archive(readme: &mut String, year: Year, archive_path: &str) -> Result<(), Error> {
+    if !contains_marker(readme, ARCHIVE_MARKER) {
+        return Ok(());
+    }
+
+    let positions = locate_table(readme, ARCHIVE_MARKER)?;
+    let section = &readme[positions.pos_start..positions.pos_end];
+
+    let mut links: Vec<String> = section
+        .lines()
+        .filter(|x| x.starts_with("- ["))
+        .map(String::from)
+        .collect();
+
+    let link = format!("- [{year}](./{})", archive_path.trim_start_matches("./"));
+    if !links.contains(&link) {
+        links.push(link);
+    }
+    links.sort_unstable_by(|a, b| b.cmp(a));
+
+    let content = [
+        ARCHIVE_MARKER,
+        "## Previous years",
+        "",
+        &links.join("\n"),
+        ARCHIVE_MARKER,
+    ]
+    .join("\n");
+
+    readme.replace_range(positions.pos_start..positions.pos_end, &content);
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Values available to template regions of the readme.
+pub struct TemplateContext {
+    year: Option<Year>,
+    stars: usize,
+    solved_days: usize,
+    total_millis: f64,
+    last_updated: String,
+}
+
+impl TemplateContext {
+    /// Collects the values from the environment, and the verified answers and the default timings of the default year.
+    fn load() -> Self {
+        Self::load_year(Year::configured())
+    }
+
+    /// Collects the values for a year, and its verified answers and default timings.
+    fn load_year(year: Option<Year>) -> Self {
+        let answers = year.map(Answers::read_from_file).unwrap_or_default();
+        let timings = year
+            .map(|year| Timings::read_from_file(year, None))
+            .unwrap_or_default();
+
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_secs())
+            .unwrap_or_default();
+
+        TemplateContext {
+            year,
+            stars: answers
+                .data
+                .iter()
+                .map(|a| usize::from(a.part_1.is_some()) + usize::from(a.part_2.is_some()))
+                .sum(),
+            solved_days: answers
+                .data
+                .iter()
+                .filter(|a| a.part_1.is_some() && a.part_2.is_some())
+                .count(),
+            total_millis: timings.total_millis(),
+            last_updated: format_date(secs),
+        }
+    }
+
+    fn render(&self, source: &str) -> String {
+        let mut rendered = source
+            .replace("{stars}", &self.stars.to_string())
+            .replace("{solved_days}", &self.solved_days.to_string())
+            .replace("{total_runtime}", &format!("{:.2}ms", self.total_millis))
+            .replace("{last_updated}", &self.last_updated);
+
+        // keep the placeholder visible if the year is not configured.
+        if let Some(year) = self.year {
+            rendered = rendered.replace("{year}", &year.to_string());
+        }
+
+        rendered
+    }
+}
+
+/// Whether a position of the readme lies within a fenced code block, e.g. an example in the documentation.
+fn is_in_code_block(s: &str, pos: usize) -> bool {
+    s[..pos]
+        .lines()
+        .filter(|x| x.trim_start().starts_with("```"))
+        .count()
+        % 2
+        == 1
+}
+
+/// Whether `marker` occurs in the readme outside of fenced code blocks.
+fn contains_marker(s: &str, marker: &str) -> bool {
+    s.match_indices(marker)
+        .any(|(pos, _)| !is_in_code_block(s, pos))
+}
+
+/// Re-renders every template region of the readme, except examples in fenced code blocks. A region looks like this:
+/// ```md
+/// <!--- template: # Advent of Code {year} --->
+/// # Advent of Code 2024
+/// <!--- end template --->
+/// ```
+fn update_templates(s: &mut String, context: &TemplateContext) -> Result<(), Error> {
+    let mut pos = 0;
+
+    while let Some(offset) = s[pos..].find(TEMPLATE_START) {
+        let source_start = pos + offset + TEMPLATE_START.len();
+
+        if is_in_code_block(s, pos + offset) {
+            pos = source_start;
+            continue;
+        }
+
+        let source_end = s[source_start..]
+            .find(MARKER_CLOSE)
+            .map(|x| source_start + x)
+            .ok_or_else(|| Error::Parser("Could not find end of template marker.".into()))?;
+
+        let content_start = source_end + MARKER_CLOSE.len();
+
+        let content_end = s[content_start..]
+            .find(TEMPLATE_END)
+            .map(|x| content_start + x)
+            .ok_or_else(|| {
+                Error::Parser(format!("{TEMPLATE_END}: could not find end of template."))
+            })?;
+
+        let rendered = format!("\n{}\n", context.render(&s[source_start..source_end]));
+        s.replace_range(content_start..content_end, &rendered);
+        pos = content_start + rendered.len() + TEMPLATE_END.len();
+    }
+
     Ok(())
 }
 
+/// Formats a unix timestamp as an ISO 8601 date (UTC).
+fn format_date(secs: u64) -> String {
+    // see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{
+        format_date, link_archive, take_year_sections, update_content, update_templates,
+        TemplateContext, Trend, ARCHIVE_MARKER, MARKER,
+    };
+    use crate::{
+        day, template::history::History, template::timings::Timing, template::timings::Timings,
+        year,
+    };
+
+    fn get_mock_trend() -> Trend<'static> {
+        static HISTORY: History = History { data: vec![] };
+        Trend {
+            history: &HISTORY,
+            len: 10,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -131,36 +494,161 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn updates_named_tables_side_by_side() {
+        let release = "<!--- benchmarking table: release --->";
+        let laptop = "<!--- benchmarking table: laptop --->";
+        let mut s = format!("{MARKER}{MARKER}\n{release}{release}\n{laptop}{laptop}");
+        update_content(
+            &mut s,
+            year!(2024),
+            Some("laptop"),
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            Some("release"),
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+        assert_eq!(s.matches("## Benchmarks (release)").count(), 1);
+        assert_eq!(s.matches("## Benchmarks (laptop)").count(), 1);
+        assert_eq!(s.matches("## Benchmarks\n").count(), 1);
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches(laptop).count(), 2);
+    }
+
+    #[test]
+    fn skips_table_markers_in_code_blocks() {
+        let laptop = "<!--- benchmarking table: laptop --->";
+        let example = format!("```md\n{laptop}\n{laptop}\n```");
+        let mut s = format!("{example}\n{laptop}{laptop}");
+        update_content(
+            &mut s,
+            year!(2024),
+            Some("laptop"),
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+        assert!(s.starts_with(&example));
+        assert_eq!(s.matches("## Benchmarks (laptop)").count(), 1);
+
+        let mut s = format!("{example}\n{MARKER}\n## Benchmarks\n{MARKER}");
+        let sections = take_year_sections(&mut s).unwrap();
+        assert_eq!(sections, vec!["## Benchmarks"]);
+        assert_eq!(s, format!("{example}\n{MARKER}\n{MARKER}"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_named_marker_not_present() {
+        let mut s = format!("{MARKER}{MARKER}");
+        update_content(
+            &mut s,
+            year!(2024),
+            Some("laptop"),
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2024),
+            None,
+            get_mock_timings(),
+            190.0,
+            &get_mock_trend(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +657,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +668,163 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_trends() {
+        let mut history = History::default();
+        for time in ["12ms", "10ms", "11ms"] {
+            history.record(&Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: Some(time.into()),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            });
+        }
+        let trend = Trend {
+            history: &history,
+            len: 2,
+        };
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, year!(2024), None, get_mock_timings(), 190.0, &trend).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2024_01.rs) | `10ms` ▁█ (best 10.0ms) | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2024_02.rs) | `30ms` | `40ms` |"));
+    }
+
+    fn get_mock_context() -> TemplateContext {
+        TemplateContext {
+            year: Some(year!(2024)),
+            stars: 9,
+            solved_days: 4,
+            total_millis: 10.678,
+            last_updated: "2024-12-05".into(),
+        }
+    }
+
+    #[test]
+    fn renders_templates() {
+        let mut s = [
+            "<!--- template: # Advent of Code {year} --->",
+            "# Advent of Code {year}",
+            "<!--- end template --->",
+            "foo",
+            "<!--- template: {stars} ⭐ on {solved_days} days, {total_runtime} total, {unknown} ({last_updated}) --->",
+            "<!--- end template --->",
+        ]
+        .join("\n");
+        update_templates(&mut s, &get_mock_context()).unwrap();
+        update_templates(&mut s, &get_mock_context()).unwrap();
+        let expected = [
+            "<!--- template: # Advent of Code {year} --->",
+            "# Advent of Code 2024",
+            "<!--- end template --->",
+            "foo",
+            "<!--- template: {stars} ⭐ on {solved_days} days, {total_runtime} total, {unknown} ({last_updated}) --->",
+            "9 ⭐ on 4 days, 10.68ms total, {unknown} (2024-12-05)",
+            "<!--- end template --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn skips_templates_in_code_blocks() {
+        let mut s = [
+            "```md",
+            "<!--- template: {stars} ⭐ in {year}, {total_runtime} total --->",
+            "<!--- end template --->",
+            "```",
+            "<!--- template: {total_runtime} total --->",
+            "<!--- end template --->",
+        ]
+        .join("\n");
+        let context = TemplateContext {
+            total_millis: Timings::default().total_millis(),
+            ..get_mock_context()
+        };
+        update_templates(&mut s, &context).unwrap();
+        let expected = [
+            "```md",
+            "<!--- template: {stars} ⭐ in {year}, {total_runtime} total --->",
+            "<!--- end template --->",
+            "```",
+            "<!--- template: {total_runtime} total --->",
+            "0.00ms total",
+            "<!--- end template --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn keeps_year_placeholder_if_unset() {
+        let mut s = "<!--- template: {year} ---><!--- end template --->".to_string();
+        let context = TemplateContext {
+            year: None,
+            ..get_mock_context()
+        };
+        update_templates(&mut s, &context).unwrap();
+        assert_eq!(
+            s,
+            "<!--- template: {year} --->\n{year}\n<!--- end template --->"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_template_end_missing() {
+        let mut s = "<!--- template: {year} --->\n".to_string();
+        update_templates(&mut s, &get_mock_context()).unwrap();
+    }
+
+    #[test]
+    fn takes_year_sections() {
+        let laptop = "<!--- benchmarking table: laptop --->";
+        let leaderboard = crate::template::leaderboard::MARKER;
+        let mut s = format!(
+            "foo\n{MARKER}\n## Benchmarks\n{MARKER}\n{laptop}\n## Laptop\n{laptop}\n{leaderboard}\n{leaderboard}\nbar"
+        );
+
+        let sections = take_year_sections(&mut s).unwrap();
+        assert_eq!(sections, vec!["## Benchmarks", "## Laptop"]);
+        assert_eq!(
+            s,
+            format!(
+                "foo\n{MARKER}\n{MARKER}\n{laptop}\n{laptop}\n{leaderboard}\n{leaderboard}\nbar"
+            )
+        );
+
+        let mut s = format!("{MARKER}");
+        assert!(take_year_sections(&mut s).is_err());
+    }
+
+    #[test]
+    fn links_archives() {
+        let mut s = format!("foo\n{ARCHIVE_MARKER}\n{ARCHIVE_MARKER}\nbar");
+        link_archive(&mut s, year!(2023), "data/2023/README.md").unwrap();
+        link_archive(&mut s, year!(2024), "./data/2024/README.md").unwrap();
+        link_archive(&mut s, year!(2024), "data/2024/README.md").unwrap();
+
+        let expected = [
+            "foo",
+            ARCHIVE_MARKER,
+            "## Previous years",
+            "",
+            "- [2024](./data/2024/README.md)",
+            "- [2023](./data/2023/README.md)",
+            ARCHIVE_MARKER,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_733_356_800), "2024-12-05");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+    }
 }
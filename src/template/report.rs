@@ -0,0 +1,321 @@
+/// Module that renders stored timings into shareable reports.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::history::History;
+use crate::template::readme_benchmarks::{self, Trend};
+use crate::template::timings::{Histogram, PartTiming, Timing, Timings};
+use crate::template::Year;
+
+/// The output format of `cargo report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+    Markdown,
+    Html,
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            _ => Err(ReportFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`ReportFormat`].
+#[derive(Debug)]
+pub struct ReportFormatFromStrError;
+
+impl Error for ReportFormatFromStrError {}
+
+impl Display for ReportFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a report format of csv, json, md or html")
+    }
+}
+
+pub fn render(
+    timings: Timings,
+    year: Year,
+    table: Option<&str>,
+    history: &History,
+    format: ReportFormat,
+) -> String {
+    match format {
+        ReportFormat::Csv => render_csv(&timings),
+        ReportFormat::Json => JsonValue::from(timings)
+            .stringify()
+            .unwrap_or_else(|_| "{}".into()),
+        ReportFormat::Markdown => {
+            let total_millis = timings.total_millis();
+            let trend = Trend { history, len: 10 };
+            readme_benchmarks::format_table("#", year, table, timings, total_millis, &trend)
+        }
+        ReportFormat::Html => render_html(&timings),
+    }
+}
+
+fn render_csv(timings: &Timings) -> String {
+    let mut lines = vec!["day,part_1,part_2,part_1_nanos,part_2_nanos,total_nanos".to_string()];
+
+    for timing in &timings.data {
+        lines.push(format!(
+            "{},{},{},{},{},{}",
+            timing.day,
+            format_part(timing.part_1.as_ref(), |x| x.time.clone()),
+            format_part(timing.part_2.as_ref(), |x| x.time.clone()),
+            format_part(timing.part_1.as_ref(), |x| x.nanos.to_string()),
+            format_part(timing.part_2.as_ref(), |x| x.nanos.to_string()),
+            timing.total_nanos
+        ));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn format_part(part: Option<&PartTiming>, f: impl Fn(&PartTiming) -> String) -> String {
+    part.map(f).unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+static HTML_STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem auto; max-width: 60rem; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ccc; padding: 0.4rem 0.8rem; text-align: center; }
+th { background: #f3f3f3; cursor: pointer; user-select: none; }
+th[data-order=asc]::after { content: \" ▲\"; }
+th[data-order=desc]::after { content: \" ▼\"; }
+.histograms { display: grid; grid-template-columns: repeat(auto-fill, minmax(18rem, 1fr)); gap: 1rem; }
+figure { margin: 0; }
+figcaption { font-size: 0.8rem; color: #666; }
+svg rect { fill: #2f7d32; }
+";
+
+static HTML_SCRIPT: &str = "
+document.querySelectorAll('table.sortable th').forEach((th, column) => {
+  th.addEventListener('click', () => {
+    const tbody = th.closest('table').querySelector('tbody');
+    const order = th.dataset.order === 'asc' ? 'desc' : 'asc';
+    th.closest('tr').querySelectorAll('th').forEach((x) => delete x.dataset.order);
+    th.dataset.order = order;
+    const key = (row) => parseFloat(row.children[column].dataset.sort);
+    const rows = [...tbody.rows].sort((a, b) => {
+      const [x, y] = [key(a), key(b)];
+      if (isNaN(x)) return 1;
+      if (isNaN(y)) return -1;
+      return order === 'asc' ? x - y : y - x;
+    });
+    rows.forEach((row) => tbody.appendChild(row));
+  });
+});
+";
+
+fn render_html(timings: &Timings) -> String {
+    let mut rows = String::new();
+    let mut histograms = String::new();
+
+    for timing in &timings.data {
+        rows.push_str(&format!(
+            "<tr><td data-sort=\"{}\">Day {}</td>{}{}<td data-sort=\"{}\">{:.2}ms</td></tr>\n",
+            timing.day.into_inner(),
+            timing.day.into_inner(),
+            html_time_cell(timing.part_1.as_ref()),
+            html_time_cell(timing.part_2.as_ref()),
+            timing.total_nanos,
+            timing.total_nanos / 1_000_000_f64
+        ));
+        histograms.push_str(&html_histograms(timing));
+    }
+
+    if histograms.is_empty() {
+        histograms =
+            "<p>No samples stored. Run <code>cargo time --store</code> to collect them.</p>".into();
+    }
+
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Benchmarks</title>
+<style>{HTML_STYLE}</style>
+</head>
+<body>
+<h1>Benchmarks</h1>
+<table class=\"sortable\">
+<thead><tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Total</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<p><strong>Total: {:.2}ms</strong></p>
+<h2>Sample distributions</h2>
+<div class=\"histograms\">
+{histograms}</div>
+<script>{HTML_SCRIPT}</script>
+</body>
+</html>
+",
+        timings.total_millis()
+    )
+}
+
+fn html_time_cell(part: Option<&PartTiming>) -> String {
+    match part {
+        Some(part) => format!(
+            "<td data-sort=\"{}\">{}</td>",
+            part.nanos,
+            escape_html(&part.time)
+        ),
+        None => "<td data-sort=\"NaN\">-</td>".into(),
+    }
+}
+
+fn html_histograms(timing: &Timing) -> String {
+    [("Part 1", &timing.part_1), ("Part 2", &timing.part_2)]
+        .into_iter()
+        .filter_map(|(part, part_timing)| {
+            part_timing.as_ref()?.histogram.as_ref().map(|histogram| {
+                format!(
+                "<figure>{}<figcaption>Day {} · {part} · {:.0}ns – {:.0}ns</figcaption></figure>\n",
+                histogram_svg(histogram),
+                timing.day.into_inner(),
+                histogram.min_nanos,
+                histogram.max_nanos
+            )
+            })
+        })
+        .collect()
+}
+
+fn histogram_svg(histogram: &Histogram) -> String {
+    let height = 60_f64;
+    let bar_width = 10_f64;
+    let max_count = f64::from(histogram.buckets.iter().copied().max().unwrap_or(0).max(1));
+
+    let bars: String = histogram
+        .buckets
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let bar_height = f64::from(*count) / max_count * height;
+            format!(
+                "<rect x=\"{}\" y=\"{:.1}\" width=\"{}\" height=\"{bar_height:.1}\"><title>{count}</title></rect>",
+                i as f64 * bar_width,
+                height - bar_height,
+                bar_width - 1_f64,
+            )
+        })
+        .collect();
+
+    format!(
+        "<svg viewBox=\"0 0 {} {height}\" width=\"100%\" height=\"{height}\" preserveAspectRatio=\"none\">{bars}</svg>",
+        histogram.buckets.len() as f64 * bar_width
+    )
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, ReportFormat};
+    use crate::{
+        day,
+        template::history::History,
+        template::timings::{Histogram, PartTiming, Timing, Timings},
+        year,
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some(PartTiming {
+                        histogram: Histogram::from_samples(&[1.0, 2.0, 3.0]),
+                        ..PartTiming::from("10ms")
+                    }),
+                    part_2: Some("20µs".into()),
+                    total_nanos: 10_020_000_f64,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30ms".into()),
+                    part_2: None,
+                    total_nanos: 30_000_000_f64,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("csv".parse::<ReportFormat>().unwrap(), ReportFormat::Csv);
+        assert_eq!(
+            "md".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Markdown
+        );
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = render(
+            get_mock_timings(),
+            year!(2024),
+            None,
+            &History::default(),
+            ReportFormat::Csv,
+        );
+        let expected = [
+            "day,part_1,part_2,part_1_nanos,part_2_nanos,total_nanos",
+            "01,10ms,20µs,10000000,20000,10020000",
+            "02,30ms,,30000000,,30000000",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn renders_markdown_without_markers() {
+        let md = render(
+            get_mock_timings(),
+            year!(2024),
+            None,
+            &History::default(),
+            ReportFormat::Markdown,
+        );
+        assert!(md.starts_with("# Benchmarks"));
+        assert!(!md.contains("<!---"));
+    }
+
+    #[test]
+    fn renders_html_with_histograms() {
+        let html = render(
+            get_mock_timings(),
+            year!(2024),
+            None,
+            &History::default(),
+            ReportFormat::Html,
+        );
+        assert!(html.contains("<table class=\"sortable\">"));
+        assert_eq!(html.matches("<figure>").count(), 1);
+        assert!(html.contains("data-sort=\"20000\""));
+    }
+}
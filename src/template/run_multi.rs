@@ -1,19 +1,25 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, path::PathBuf};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::config::{project_root, relative_to_cwd};
+use crate::template::{Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
             if need_space {
@@ -24,7 +30,9 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output =
+                child_commands::run_solution(PuzzleId::new(year, day), is_timed, is_release)
+                    .unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -59,16 +67,27 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Returns the path of the solution of a puzzle, e.g. `src/bin/2024_01.rs` in the root of the project.
+/// Relative to the working directory if it is within it, to keep paths in messages short.
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    relative_to_cwd(get_bin_dir().join(format!("{}.rs", puzzle.bin_name())))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns the folder of the solution binaries, i.e. `src/bin` in the root of the project.
+pub fn get_bin_dir() -> PathBuf {
+    project_root().join("src/bin")
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::runner::{SAMPLES_ENV_VAR, SAMPLES_PREFIX};
+    use crate::template::timings::{Histogram, PartTiming};
+    use crate::template::{Day, PuzzleId, ANSI_BOLD, ANSI_RESET};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -76,15 +95,19 @@ pub mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.bin_name();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -99,11 +122,14 @@ pub mod child_commands {
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut cmd = Command::new("cargo");
+        cmd.args(&args);
+
+        if is_timed {
+            cmd.env(SAMPLES_ENV_VAR, "1");
+        }
+
+        let mut cmd = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
@@ -118,7 +144,10 @@ pub mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
+            // sample lines are only meant for `parse_exec_time`.
+            if !line.starts_with(SAMPLES_PREFIX) {
+                println!("{line}");
+            }
             output.push(line);
         }
 
@@ -149,21 +178,70 @@ pub mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((l, part, timing_str, nanos))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(line, part, timing_str, nanos)| {
+                let part_timing = Some(PartTiming {
+                    time: timing_str.into(),
+                    nanos,
+                    answer: parse_answer(line).map(Into::into),
+                    histogram: None,
+                });
+
                 if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
+                    timings.part_1 = part_timing;
                 } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                    timings.part_2 = part_timing;
                 }
 
                 timings.total_nanos += nanos;
             });
 
+        output
+            .iter()
+            .filter_map(|l| l.strip_prefix(SAMPLES_PREFIX))
+            .filter_map(|l| {
+                let (part, samples) = l.split_once(':')?;
+                let samples = samples
+                    .trim()
+                    .split(',')
+                    .map(|x| x.parse::<f64>().ok())
+                    .collect::<Option<Vec<_>>>()?;
+                Some((part, Histogram::from_samples(&samples)?))
+            })
+            .for_each(|(part, histogram)| {
+                let part_timing = if part.contains("Part 1") {
+                    timings.part_1.as_mut()
+                } else if part.contains("Part 2") {
+                    timings.part_2.as_mut()
+                } else {
+                    None
+                };
+
+                if let Some(part_timing) = part_timing {
+                    part_timing.histogram = Some(histogram);
+                }
+            });
+
         timings
     }
 
+    /// Parses the (bold) result of a part from its output line. Multi-line results are not captured.
+    fn parse_answer(line: &str) -> Option<&str> {
+        // the runner prints an intermediate result before benching and overwrites it with `\r`.
+        let line = line.rsplit('\r').next()?;
+
+        if let Some((_, rest)) = line.split_once(ANSI_BOLD) {
+            let (answer, _) = rest.split_once(ANSI_RESET)?;
+            return Some(answer);
+        }
+
+        // without colours, the result is followed by its timing, e.g. `Part 1: 42 (1.2ms @ 10 samples)`.
+        let (_, rest) = line.split_once(": ")?;
+        let (answer, _) = rest.rsplit_once(" (")?;
+        (!answer.starts_with('▼')).then_some(answer)
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -174,19 +252,24 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
+        let parsed_timing = parse_duration(str_timing)?;
+
+        Some((str_timing, parsed_timing))
+    }
+
+    /// Parses a formatted duration such as `74.13ms` to nanoseconds.
+    pub fn parse_duration(s: &str) -> Option<f64> {
+        match s {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -220,8 +303,8 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.part_1.unwrap().time, "74.13ns");
+            assert_eq!(res.part_2.unwrap().time, "74.13ms");
         }
 
         #[test]
@@ -235,8 +318,50 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap().time, "2s");
+            assert_eq!(res.part_2.unwrap().time, "100ms");
+        }
+
+        #[test]
+        fn parses_answers() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: \x1b[1m31\x1b[0m > \x1b[3mbenching\x1b[0m\rPart 1: \x1b[1m31\x1b[0m (1.8µs @ 10000 samples)".into(),
+                    "Part 2: ▼  (1.8µs @ 10000 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().answer.as_deref(), Some("31"));
+            assert_eq!(res.part_2.unwrap().answer, None);
+
+            // with `runner.color = false`.
+            let res = parse_exec_time(
+                &[
+                    "Part 1: (3, 1) > benching\rPart 1: (3, 1) (1.8µs @ 10000 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().answer.as_deref(), Some("(3, 1)"));
+        }
+
+        #[test]
+        fn parses_sample_histograms() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (2.0ns @ 3 samples)".into(),
+                    "samples Part 1: 1,2,3".into(),
+                    "Part 2: 10 (1.0ns @ 1 samples)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            let histogram = res.part_1.unwrap().histogram.unwrap();
+            assert_approx_eq!(histogram.min_nanos, 1_f64);
+            assert_approx_eq!(histogram.max_nanos, 3_f64);
+            assert_eq!(histogram.buckets.iter().sum::<u32>(), 3);
+            assert_eq!(res.part_2.unwrap().histogram.is_none(), true);
         }
 
         #[test]
@@ -2,23 +2,83 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::AocClient;
+use crate::template::submissions::{submit_answer, Submission, Submissions, Verdict};
+use crate::template::{aoc_client, config, Part, PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// When this environment variable is set, benched parts print their individual samples on an extra line.
+/// `cargo time` uses this to store sample histograms alongside the timings.
+pub const SAMPLES_ENV_VAR: &str = "AOC_BENCH_SAMPLES";
+
+/// Prefix of the extra line printed for [`SAMPLES_ENV_VAR`], e.g. `samples Part 1: 120,118,131`.
+pub const SAMPLES_PREFIX: &str = "samples ";
+
+/// The arguments `cargo solve` and `cargo time` pass on to a solution binary.
+#[derive(Debug, Default)]
+pub struct RunnerArgs {
+    /// Bench every part, passed as `--time`.
+    pub time: bool,
+    /// The part to submit, passed as `--submit <part>`.
+    pub submit: Option<Part>,
+    /// Submit answers that were rejected before, passed as `--force`.
+    pub force: bool,
+    /// Wait out cooldowns, passed as `--wait`.
+    pub wait: bool,
+}
+
+impl RunnerArgs {
+    /// Parses the arguments of the current process. Exits on malformed input.
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+
+        let submit = match args.opt_value_from_str("--submit") {
+            Ok(submit) => submit,
+            Err(e) => {
+                eprintln!("Unexpected command-line input: {e}. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            }
+        };
+
+        Self {
+            time: args.contains("--time"),
+            submit,
+            force: args.contains("--force"),
+            wait: args.contains("--wait"),
+        }
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: Part,
+    args: &RunnerArgs,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples) = run_timed(func, input, args.time, |result| {
+        print_result(result, &part_str, "");
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, samples.len() as u128),
+    );
+
+    if samples.len() > 1 && env::var_os(SAMPLES_ENV_VAR).is_some() {
+        print_samples(&part_str, &samples);
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if args.submit == Some(part) {
+            submit_result(result, puzzle, part, args);
+        }
     }
 }
 
@@ -28,8 +88,9 @@ pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, d
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Vec<Duration>) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,23 +104,28 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if is_timed {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        (base_time, vec![base_time])
     };
 
     (result, run.0, run.1)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, Vec<Duration>) {
     let mut stdout = stdout();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    print!(" > {}benching{}", style(ANSI_ITALIC), style(ANSI_RESET));
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let runner = &config().runner;
+    let bench_iterations = (runner.bench_budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(runner.min_samples.into(), runner.max_samples.into());
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -74,7 +140,7 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     (
         #[allow(clippy::cast_possible_truncation)]
         Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
+        timers,
     )
 }
 
@@ -94,6 +160,24 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+fn print_samples(part: &str, samples: &[Duration]) {
+    let samples = samples
+        .iter()
+        .map(|x| x.as_nanos().to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    println!("{SAMPLES_PREFIX}{part}: {samples}");
+}
+
+/// Returns the ANSI escape code, or nothing if colours are disabled with `runner.color` in `aoc.toml`.
+pub(crate) fn style(code: &str) -> &str {
+    if config().runner.color {
+        code
+    } else {
+        ""
+    }
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -109,7 +193,11 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str = format!(
+                    "{part}: {}{result}{}{duration_str}",
+                    style(ANSI_BOLD),
+                    style(ANSI_RESET)
+                );
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -129,41 +217,63 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+/// Submits the result of a part.
+/// Every submission is recorded. Answers that were rejected before are refused unless `--force` is passed.
+/// With `--wait`, a cooldown is waited out and the answer is submitted again.
+/// Exits with the code of the verdict (see [`Verdict::exit_code`]) unless the part was solved.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: Part, args: &RunnerArgs) {
+    let answer = result.to_string();
+    let mut submissions = Submissions::read_from_file(puzzle.year);
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if !args.force {
+        if let Err(e) = submissions.check(puzzle.day, part, &answer) {
+            eprintln!("Refusing to submit: {e} Pass --force to submit anyway.");
+            process::exit(1);
+        }
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    println!("Submitting result...");
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let result = AocClient::from_env()
+        .and_then(|client| submit_answer(&client, puzzle, part, &answer, args.wait));
+
+    let (mut verdict, message) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(3);
+        }
     };
 
-    if part_submit != part {
-        return None;
+    let mut answers = Answers::read_from_file(puzzle.year);
+
+    // the website does not distinguish solved parts from locked ones.
+    if verdict == Verdict::WrongLevel && answers.get(puzzle.day, part).is_some() {
+        verdict = Verdict::AlreadySolved;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    println!("{}", verdict.summary(puzzle.day, part, &answer));
+
+    if verdict == Verdict::Unknown {
+        println!("{message}");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    submissions.record(Submission::new(puzzle.day, part, &answer, verdict));
+    if let Err(e) = submissions.store_file(puzzle.year) {
+        eprintln!("failed to store submission: {e}");
+    }
+
+    if verdict == Verdict::Correct {
+        answers.set(puzzle.day, part, &answer);
+        if let Err(e) = answers.store_file(puzzle.year) {
+            eprintln!("failed to store answer: {e}");
+        }
+        if let Err(e) = aoc_client::download_puzzle(puzzle) {
+            eprintln!("failed to download puzzle: {e}");
+        }
+    }
+
+    if verdict.exit_code() != 0 {
+        process::exit(verdict.exit_code());
+    }
 }
@@ -0,0 +1,170 @@
+//! Module that collects the progress of every day of a year and renders it as a calendar.
+
+use std::{fs, path::Path};
+
+use crate::template::answers::Answers;
+use crate::template::encryption::{self, encrypted_path};
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::Timings;
+use crate::template::{get_data_path, Day, Part, PuzzleId, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Number of days in a row of the calendar.
+const DAYS_PER_ROW: usize = 5;
+
+/// Marks a step of a day that was not done yet.
+const MISSING: char = '·';
+
+/// The progress of a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayStatus {
+    pub day: Day,
+    /// The solution exists in `src/bin`.
+    pub scaffolded: bool,
+    /// The input was downloaded.
+    pub input: bool,
+    /// The example file has contents.
+    pub example: bool,
+    /// The puzzle description was downloaded.
+    pub puzzle: bool,
+    /// Whether the tests of the solution pass, `None` if they were not run.
+    pub tests: Option<bool>,
+    /// Number of parts with a verified answer.
+    pub stars: usize,
+    /// Both parts have a stored timing.
+    pub benched: bool,
+}
+
+impl DayStatus {
+    /// Checks the files and stores of a puzzle. Tests are not run.
+    pub fn read(puzzle: PuzzleId, answers: &Answers, timings: &Timings) -> Self {
+        let data_path = |folder: &str, extension: &str| {
+            get_data_path(puzzle.year, &format!("{folder}/{}.{extension}", puzzle.day))
+        };
+
+        DayStatus {
+            day: puzzle.day,
+            scaffolded: Path::new(&get_path_for_bin(puzzle)).exists(),
+            input: has_contents(&data_path("inputs", "txt")),
+            example: has_contents(&data_path("examples", "txt")),
+            puzzle: encryption::exists(&data_path("puzzles", "md")),
+            tests: None,
+            stars: Part::ALL
+                .iter()
+                .filter(|part| answers.get(puzzle.day, **part).is_some())
+                .count(),
+            benched: timings.is_day_complete(puzzle.day),
+        }
+    }
+
+    /// Formats the day followed by a marker for each step, e.g. `01 SIEPT**B`.
+    fn format_cell(&self) -> String {
+        let mark = |done: bool, c: char| if done { c } else { MISSING };
+
+        let tests = match self.tests {
+            Some(true) => 'T',
+            Some(false) => '!',
+            None => '?',
+        };
+
+        let mut cell = format!(
+            "{} {}{}{}{}{tests}",
+            self.day,
+            mark(self.scaffolded, 'S'),
+            mark(self.input, 'I'),
+            mark(self.example, 'E'),
+            mark(self.puzzle, 'P'),
+        );
+
+        for part in 0..Part::ALL.len() {
+            cell.push(mark(part < self.stars, '*'));
+        }
+
+        cell.push(mark(self.benched, 'B'));
+        cell
+    }
+}
+
+fn has_contents(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|x| x.len() > 0)
+        || fs::metadata(encrypted_path(path)).is_ok_and(|x| x.len() > 0)
+}
+
+/// Renders the days of a year as a calendar grid, followed by the number of stars and a legend.
+pub fn format_calendar(year: Year, days: &[DayStatus]) -> String {
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}"),
+        String::new(),
+    ];
+
+    for row in days.chunks(DAYS_PER_ROW) {
+        let cells: Vec<String> = row.iter().map(DayStatus::format_cell).collect();
+        lines.push(cells.join("   "));
+    }
+
+    let stars: usize = days.iter().map(|x| x.stars).sum();
+    lines.push(String::new());
+    lines.push(format!("⭐ {stars}/{} stars", days.len() * Part::ALL.len()));
+    lines.push(String::new());
+    lines.push(format!(
+        "S scaffolded  I input  E example  P puzzle  T tests pass (! failing, ? not run)  * verified answer  B benched  {MISSING} missing"
+    ));
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_calendar, DayStatus};
+    use crate::template::all_days;
+    use crate::year;
+
+    fn get_status(day: crate::template::Day) -> DayStatus {
+        DayStatus {
+            day,
+            scaffolded: false,
+            input: false,
+            example: false,
+            puzzle: false,
+            tests: None,
+            stars: 0,
+            benched: false,
+        }
+    }
+
+    #[test]
+    fn formats_cells() {
+        let mut days: Vec<DayStatus> = all_days(year!(2025)).map(get_status).collect();
+        days[0] = DayStatus {
+            scaffolded: true,
+            input: true,
+            example: true,
+            puzzle: true,
+            tests: Some(true),
+            stars: 2,
+            benched: true,
+            ..days[0].clone()
+        };
+        days[1] = DayStatus {
+            scaffolded: true,
+            example: true,
+            tests: Some(false),
+            stars: 1,
+            ..days[1].clone()
+        };
+
+        assert_eq!(days[0].format_cell(), "01 SIEPT**B");
+        assert_eq!(days[1].format_cell(), "02 S·E·!*··");
+        assert_eq!(days[2].format_cell(), "03 ····?···");
+
+        let calendar = format_calendar(year!(2025), &days);
+        let lines: Vec<&str> = calendar.lines().collect();
+        assert_eq!(
+            lines[2],
+            "01 SIEPT**B   02 S·E·!*··   03 ····?···   04 ····?···   05 ····?···"
+        );
+        assert_eq!(lines[4], "11 ····?···   12 ····?···");
+        assert_eq!(lines[6], "⭐ 3/24 stars");
+    }
+}
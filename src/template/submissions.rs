@@ -0,0 +1,568 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, stdout, Write},
+    str::FromStr,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{AocClient, AocCommandError};
+use crate::template::{create_data_file, get_data_path, Day, Part, PuzzleId, Year};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// Extra seconds to wait after a cooldown, so the resubmission does not arrive early.
+static COOLDOWN_MARGIN_SECS: u64 = 1;
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after a wrong one and has to be resubmitted after the given seconds.
+    Wait(u64),
+    /// The part was already solved.
+    AlreadySolved,
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+    /// The response could not be classified.
+    Unknown,
+}
+
+impl Verdict {
+    /// Classifies the message the website responded with.
+    pub fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            parse_wait(message).map_or(Verdict::Unknown, Verdict::Wait)
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// A one-line summary of the verdict on an answer.
+    pub fn summary(self, day: Day, part: Part, answer: &str) -> String {
+        let (icon, text) = match self {
+            Verdict::Correct => ("⭐", "is correct!".to_string()),
+            Verdict::Wrong => ("❌", "is wrong.".to_string()),
+            Verdict::TooHigh => ("❌", "is wrong, it is too high.".to_string()),
+            Verdict::TooLow => ("❌", "is wrong, it is too low.".to_string()),
+            Verdict::Wait(secs) => (
+                "⏳",
+                format!("was not checked, wait {secs}s before submitting again."),
+            ),
+            Verdict::AlreadySolved => {
+                ("✔️", "was not checked, this part is already solved.".into())
+            }
+            Verdict::WrongLevel => (
+                "⚠️",
+                "was not checked, this part is already solved or not unlocked yet.".into(),
+            ),
+            Verdict::Unknown => (
+                "⚠️",
+                "was not checked, the response was not understood.".into(),
+            ),
+        };
+
+        format!("{icon} Day {day}, part {part}: {answer} {text}")
+    }
+
+    /// The exit code of `cargo solve --submit`:
+    /// `0` if the part is solved, `1` if the answer is wrong, `2` during a cooldown and `3` otherwise.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Verdict::Correct | Verdict::AlreadySolved => 0,
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => 1,
+            Verdict::Wait(_) => 2,
+            Verdict::WrongLevel | Verdict::Unknown => 3,
+        }
+    }
+
+    /// Returns `true` if the answer is known to be wrong.
+    pub fn is_rejected(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wait(_) => "wait",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Parses the remaining cooldown of a message such as "You have 1m 5s left to wait.", in seconds.
+fn parse_wait(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    time.split_whitespace()
+        .map(|x| {
+            let (value, unit) = x.split_at(x.len().checked_sub(1)?);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "s" => Some(value),
+                "m" => Some(value * 60),
+                "h" => Some(value * 3600),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wait" => Ok(Verdict::Wait(0)),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single answer submitted to the website.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: Part, answer: &str, verdict: Verdict) -> Self {
+        Submission {
+            day,
+            part,
+            answer: answer.into(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|x| x.as_secs())
+                .unwrap_or_default(),
+            verdict,
+        }
+    }
+}
+
+/// Represents every answer submitted for the puzzles of a year so far, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to the JSON file of a year, e.g. `data/2024/submissions.json`.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = create_data_file(&get_data_path(year, SUBMISSIONS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from the JSON file of a year. If not present, returns empty submissions.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_data_path(year, SUBMISSIONS_FILE_NAME))
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.data.push(submission);
+    }
+
+    /// Checks an answer against earlier submissions of the same part.
+    /// Refuses answers that were rejected before or that lie outside the bounds learned from "too high" / "too low" hints.
+    pub fn check(&self, day: Day, part: Part, answer: &str) -> Result<(), SubmissionRefusal> {
+        let rejected = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.verdict.is_rejected());
+
+        let mut too_high: Option<(i128, &str)> = None;
+        let mut too_low: Option<(i128, &str)> = None;
+
+        for submission in rejected {
+            if submission.answer == answer {
+                return Err(SubmissionRefusal::AlreadyRejected(answer.into()));
+            }
+
+            let Ok(value) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match submission.verdict {
+                Verdict::TooHigh if too_high.is_none_or(|(x, _)| value < x) => {
+                    too_high = Some((value, &submission.answer));
+                }
+                Verdict::TooLow if too_low.is_none_or(|(x, _)| value > x) => {
+                    too_low = Some((value, &submission.answer));
+                }
+                _ => {}
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        match (too_high, too_low) {
+            (Some((bound, bound_str)), _) if value >= bound => Err(SubmissionRefusal::TooHigh {
+                answer: answer.into(),
+                bound: bound_str.into(),
+            }),
+            (_, Some((bound, bound_str))) if value <= bound => Err(SubmissionRefusal::TooLow {
+                answer: answer.into(),
+                bound: bound_str.into(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Submits an answer and classifies the response.
+/// If `wait` is set, cooldowns are waited out with a countdown before the answer is submitted again.
+pub fn submit_answer(
+    client: &AocClient,
+    puzzle: PuzzleId,
+    part: Part,
+    answer: &str,
+    wait: bool,
+) -> Result<(Verdict, String), AocCommandError> {
+    loop {
+        let message = client.submit(puzzle, part, answer)?;
+
+        match Verdict::from_message(&message) {
+            Verdict::Wait(secs) if wait => countdown(secs + COOLDOWN_MARGIN_SECS),
+            verdict => return Ok((verdict, message)),
+        }
+    }
+}
+
+fn countdown(secs: u64) {
+    for remaining in (1..=secs).rev() {
+        print!("\r⏳ Cooldown, submitting again in {remaining}s... ");
+        let _ = stdout().flush();
+        thread::sleep(Duration::from_secs(1));
+    }
+    println!();
+}
+
+/// The reason an answer was not submitted.
+#[derive(Debug, PartialEq)]
+pub enum SubmissionRefusal {
+    AlreadyRejected(String),
+    TooHigh { answer: String, bound: String },
+    TooLow { answer: String, bound: String },
+}
+
+impl Error for SubmissionRefusal {}
+
+impl Display for SubmissionRefusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionRefusal::AlreadyRejected(answer) => {
+                write!(f, "{answer} was already submitted and rejected.")
+            }
+            SubmissionRefusal::TooHigh { answer, bound } => {
+                write!(f, "{answer} is too high, {bound} was already too high.")
+            }
+            SubmissionRefusal::TooLow { answer, bound } => {
+                write!(f, "{answer} is too low, {bound} was already too low.")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part".into(),
+            JsonValue::Number(value.part.into_inner().into()),
+        );
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        if let Verdict::Wait(secs) = value.verdict {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("wait".into(), JsonValue::Number(secs as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|x| match *x {
+                1.0 => Some(Part::One),
+                2.0 => Some(Part::Two),
+                _ => None,
+            })
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let mut verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        // the cooldown is optional for backwards compatibility.
+        if let (Verdict::Wait(_), Some(secs)) = (verdict, json.get("wait")) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let secs = secs
+                .get::<f64>()
+                .map(|x| *x as u64)
+                .ok_or("Expected submission.wait to be a number.")?;
+            verdict = Verdict::Wait(secs);
+        }
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            timestamp,
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{submit_answer, Submission, SubmissionRefusal, Submissions, Verdict};
+    use crate::template::{aoc_client::stub::serve_all, Part, PuzzleId};
+    use crate::{day, year};
+
+    fn get_mock_submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.record(Submission::new(day!(1), Part::One, "100", Verdict::TooHigh));
+        submissions.record(Submission::new(day!(1), Part::One, "120", Verdict::TooHigh));
+        submissions.record(Submission::new(day!(1), Part::One, "20", Verdict::TooLow));
+        submissions.record(Submission::new(day!(1), Part::One, "50", Verdict::Wrong));
+        submissions.record(Submission::new(day!(1), Part::Two, "70", Verdict::Wait(30)));
+        submissions
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_message("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_message(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."
+            ),
+            Verdict::Wait(65)
+        );
+        assert_eq!(
+            Verdict::from_message(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::from_message("Oops."), Verdict::Unknown);
+    }
+
+    #[test]
+    fn summarizes_verdicts() {
+        assert_eq!(
+            Verdict::TooLow.summary(day!(3), Part::Two, "12"),
+            "❌ Day 03, part 2: 12 is wrong, it is too low."
+        );
+        assert_eq!(Verdict::Correct.exit_code(), 0);
+        assert_eq!(Verdict::Wait(5).exit_code(), 2);
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.check(day!(1), Part::One, "50"),
+            Err(SubmissionRefusal::AlreadyRejected("50".into()))
+        );
+        assert_eq!(submissions.check(day!(1), Part::Two, "70"), Ok(()));
+        assert_eq!(submissions.check(day!(2), Part::One, "50"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.check(day!(1), Part::One, "110"),
+            Err(SubmissionRefusal::TooHigh {
+                answer: "110".into(),
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(day!(1), Part::One, "-3"),
+            Err(SubmissionRefusal::TooLow {
+                answer: "-3".into(),
+                bound: "20".into()
+            })
+        );
+        assert_eq!(submissions.check(day!(1), Part::One, "99"), Ok(()));
+        assert_eq!(submissions.check(day!(1), Part::One, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn waits_out_cooldowns() {
+        let cooldown = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1s left to wait.</p></article>";
+        let correct = "<article><p>That's the right answer!</p></article>";
+
+        let (client, requests) = serve_all(&[("200 OK", cooldown), ("200 OK", correct)]);
+        let puzzle = PuzzleId::new(year!(2024), day!(1));
+        let (verdict, _) = submit_answer(&client, puzzle, Part::Two, "42", true).unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+        assert_eq!(requests.iter().take(2).count(), 2);
+
+        let (client, _) = serve_all(&[("200 OK", cooldown)]);
+        let (verdict, _) = submit_answer(&client, puzzle, Part::Two, "42", false).unwrap();
+        assert_eq!(verdict, Verdict::Wait(1));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let submissions = get_mock_submissions();
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap().data, submissions.data);
+
+        let get_json = |part: &str| {
+            format!(
+                r#"{{"data":[{{"day":"01","part":{part},"answer":"1","verdict":"correct","timestamp":0}}]}}"#
+            )
+        };
+        assert!(Submissions::try_from(get_json("2")).is_ok());
+        for part in ["1.5", "257", "-1"] {
+            assert!(Submissions::try_from(get_json(part)).is_err());
+        }
+    }
+}
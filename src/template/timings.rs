@@ -1,20 +1,41 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::answers::Answers;
+use crate::template::run_multi::child_commands::parse_duration;
+use crate::template::{config, create_data_file, get_data_path, Day, Part, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static HISTOGRAM_BUCKETS: usize = 20;
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
-/// Represents benchmark times for a set of days.
+/// Represents the benchmark time of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// The formatted duration, e.g. `1.3µs`.
+    pub time: String,
+    pub nanos: f64,
+    /// The answer the part produced while being benched.
+    pub answer: Option<String>,
+    pub histogram: Option<Histogram>,
+}
+
+/// Distribution of the benchmark samples of a single part, bucketed into bins of equal width.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    pub min_nanos: f64,
+    pub max_nanos: f64,
+    pub buckets: Vec<u32>,
+}
+
+/// Represents benchmark times for the days of a year.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -22,42 +43,82 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a benchmark table.
+    pub fn store_file(&self, year: Year, table: Option<&str>) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = create_data_file(&get_file_path(year, table))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of a benchmark table. If not present, returns empty timings.
+    pub fn read_from_file(year: Year, table: Option<&str>) -> Self {
+        fs::read_to_string(get_file_path(year, table))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
-    pub fn merge(&self, new: &Self) -> Self {
-        let mut data: Vec<Timing> = vec![];
+    /// Merge two sets of timings part by part. Parts missing from `new` are kept,
+    /// the `policy` decides whether a new part replaces a stored one.
+    /// A day that is not stored yet is only added once the policy accepts one of its parts.
+    pub fn merge(&self, new: &Self, policy: MergePolicy, answers: &Answers) -> Self {
+        let mut data: Vec<Timing> = self.data.clone();
 
         for timing in &new.data {
-            data.push(timing.clone());
-        }
+            let index = data.iter().position(|t| t.day == timing.day);
+            let mut merged = index.map_or_else(
+                || Timing {
+                    day: timing.day,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                },
+                |index| data[index].clone(),
+            );
+            let mut accepted = false;
+
+            for (part, stored_part, new_part) in [
+                (Part::One, &mut merged.part_1, &timing.part_1),
+                (Part::Two, &mut merged.part_2, &timing.part_2),
+            ] {
+                let Some(new_part) = new_part else {
+                    continue;
+                };
+
+                if policy.accepts(
+                    stored_part.as_ref(),
+                    new_part,
+                    answers.get(timing.day, part),
+                ) {
+                    *stored_part = Some(new_part.clone());
+                    accepted = true;
+                }
+            }
 
-        for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
-                data.push(timing.clone());
+            if index.is_none() && !accepted {
+                continue;
+            }
+
+            merged.total_nanos = [&merged.part_1, &merged.part_2]
+                .into_iter()
+                .flatten()
+                .map(|x| x.nanos)
+                .sum();
+
+            match index {
+                Some(index) => data[index] = merged,
+                None => data.push(merged),
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        // an empty sum of floats is `-0.0`, adding `0.0` keeps it from being printed as `-0.00ms`.
+        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64 + 0.0
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -67,6 +128,129 @@ impl Timings {
     }
 }
 
+impl From<&str> for PartTiming {
+    fn from(time: &str) -> Self {
+        PartTiming {
+            time: time.into(),
+            nanos: parse_duration(time).unwrap_or_default(),
+            answer: None,
+            histogram: None,
+        }
+    }
+}
+
+/// Decides which timing of a part is kept when new timings are merged into stored ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Always keep the newest timing.
+    #[default]
+    Latest,
+    /// Keep the fastest timing.
+    Best,
+    /// Only keep a new timing if the part produced its verified answer.
+    OnlyIfVerified,
+}
+
+impl MergePolicy {
+    fn accepts(
+        self,
+        stored: Option<&PartTiming>,
+        new: &PartTiming,
+        verified: Option<&str>,
+    ) -> bool {
+        match self {
+            MergePolicy::Latest => true,
+            MergePolicy::Best => stored.is_none_or(|stored| new.nanos < stored.nanos),
+            MergePolicy::OnlyIfVerified => verified.is_some() && new.answer.as_deref() == verified,
+        }
+    }
+}
+
+impl FromStr for MergePolicy {
+    type Err = MergePolicyFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(MergePolicy::Latest),
+            "best" => Ok(MergePolicy::Best),
+            "only-if-verified" => Ok(MergePolicy::OnlyIfVerified),
+            _ => Err(MergePolicyFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`MergePolicy`].
+#[derive(Debug)]
+pub struct MergePolicyFromStrError;
+
+impl std::error::Error for MergePolicyFromStrError {}
+
+impl Display for MergePolicyFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a policy of latest, best or only-if-verified")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Histogram {
+    /// Buckets a set of sample durations (in nanoseconds). Returns `None` if there are no samples.
+    /// The buckets span the range up to the 99th percentile, slower outliers are counted in the last bucket.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let min_nanos = *sorted.first()?;
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let max_nanos = sorted[((sorted.len() as f64 * 0.99).ceil() as usize).max(1) - 1];
+
+        let mut buckets = vec![0; HISTOGRAM_BUCKETS];
+        let width = (max_nanos - min_nanos) / HISTOGRAM_BUCKETS as f64;
+
+        for sample in &sorted {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = if width > 0.0 {
+                (((sample - min_nanos) / width) as usize).min(HISTOGRAM_BUCKETS - 1)
+            } else {
+                0
+            };
+            buckets[index] += 1;
+        }
+
+        Some(Histogram {
+            min_nanos,
+            max_nanos,
+            buckets,
+        })
+    }
+}
+
+/// Every benchmark table of a year is backed by its own file, e.g. `data/2024/timings-laptop.json` for the table `laptop`.
+pub fn get_file_path(year: Year, table: Option<&str>) -> String {
+    // the file name is checked to end with `.json` when reading the config.
+    let file_name = &config().paths.timings;
+
+    match table {
+        Some(name) => get_data_path(
+            year,
+            &format!("{}-{name}.json", file_name.trim_end_matches(".json")),
+        ),
+        None => get_data_path(year, file_name),
+    }
+}
+
+/// Checks that a table name can be used both in a README marker and a file name.
+pub fn is_valid_table_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -114,24 +298,23 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            let Some(part) = part else {
+                map.insert(key.into(), JsonValue::Null);
+                continue;
+            };
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+            map.insert(key.into(), JsonValue::String(part.time.clone()));
+            map.insert(format!("{key}_nanos"), JsonValue::Number(part.nanos));
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+            if let Some(answer) = &part.answer {
+                map.insert(format!("{key}_answer"), JsonValue::String(answer.clone()));
+            }
+
+            if let Some(histogram) = &part.histogram {
+                map.insert(format!("{key}_histogram"), JsonValue::from(histogram));
+            }
+        }
 
         JsonValue::Object(map)
     }
@@ -168,13 +351,109 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            part_1: part_1
+                .map(|time| part_timing_from_json(json, "part_1", time))
+                .transpose()?,
+            part_2: part_2
+                .map(|time| part_timing_from_json(json, "part_2", time))
+                .transpose()?,
             total_nanos,
         })
     }
 }
 
+/// Reads the optional per-part keys (`part_1_nanos`, `part_1_answer`, `part_1_histogram`) of a timing.
+/// These are optional to keep timings stored by older versions readable.
+fn part_timing_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+    time: &str,
+) -> Result<PartTiming, String> {
+    let mut part = PartTiming::from(time);
+
+    if let Some(nanos) = json.get(&format!("{key}_nanos")) {
+        part.nanos = *nanos
+            .get::<f64>()
+            .ok_or(format!("Expected timing.{key}_nanos to be a number."))?;
+    }
+
+    if let Some(answer) = json.get(&format!("{key}_answer")) {
+        part.answer = Some(
+            answer
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key}_answer to be a string."))?
+                .clone(),
+        );
+    }
+
+    part.histogram = json
+        .get(&format!("{key}_histogram"))
+        .map(Histogram::try_from)
+        .transpose()?;
+
+    Ok(part)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Histogram> for JsonValue {
+    fn from(value: &Histogram) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("min_nanos".into(), JsonValue::Number(value.min_nanos));
+        map.insert("max_nanos".into(), JsonValue::Number(value.max_nanos));
+        map.insert(
+            "buckets".into(),
+            JsonValue::Array(
+                value
+                    .buckets
+                    .iter()
+                    .map(|x| JsonValue::Number(f64::from(*x)))
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Histogram {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected histogram to be a JSON object.")?;
+
+        let min_nanos = json
+            .get("min_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected histogram.min_nanos to be a number.")?;
+
+        let max_nanos = json
+            .get("max_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected histogram.max_nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let buckets = json
+            .get("buckets")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .and_then(|v| {
+                v.iter()
+                    .map(|x| x.get::<f64>().map(|x| *x as u32))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or("Expected histogram.buckets to be an array of numbers.")?;
+
+        Ok(Histogram {
+            min_nanos,
+            max_nanos,
+            buckets,
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
@@ -218,11 +497,34 @@ mod tests {
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1.as_ref().unwrap().time, "1ms");
+            assert_eq!(timing.part_1.as_ref().unwrap().nanos, 1_000_000_f64);
+            assert_eq!(timing.part_1.as_ref().unwrap().answer, None);
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_part_details() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.3µs", "part_1_nanos": 1312, "part_1_answer": "42", "part_2": null, "total_nanos": 1312 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part = timings.data[0].part_1.as_ref().unwrap();
+            assert_eq!(part.time, "1.3µs");
+            assert_eq!(part.nanos, 1312_f64);
+            assert_eq!(part.answer.as_deref(), Some("42"));
+        }
+
+        #[test]
+        fn handles_json_histograms() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "2ns", "part_2": null, "total_nanos": 2, "part_1_histogram": { "min_nanos": 1, "max_nanos": 3, "buckets": [1, 0, 2] } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let histogram = timing.part_1.as_ref().unwrap().histogram.as_ref().unwrap();
+            assert_eq!(histogram.min_nanos, 1_f64);
+            assert_eq!(histogram.max_nanos, 3_f64);
+            assert_eq!(histogram.buckets, vec![1, 0, 2]);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,6 +549,7 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,6 +569,75 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_part_details() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1.as_mut().unwrap().answer = Some("42".into());
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+    }
+
+    mod histogram {
+        use crate::template::timings::Histogram;
+
+        #[test]
+        fn buckets_samples() {
+            let histogram = Histogram::from_samples(&[0.0, 1.0, 10.0, 20.0]).unwrap();
+            assert_eq!(histogram.min_nanos, 0_f64);
+            assert_eq!(histogram.max_nanos, 20_f64);
+            assert_eq!(histogram.buckets.len(), 20);
+            assert_eq!(histogram.buckets[0], 1);
+            assert_eq!(histogram.buckets[1], 1);
+            assert_eq!(histogram.buckets[10], 1);
+            assert_eq!(histogram.buckets[19], 1);
+        }
+
+        #[test]
+        fn clamps_outliers() {
+            let mut samples = vec![1.0; 99];
+            samples.push(1000.0);
+            samples.push(2.0);
+            let histogram = Histogram::from_samples(&samples).unwrap();
+            assert_eq!(histogram.max_nanos, 2_f64);
+            assert_eq!(histogram.buckets[19], 2);
+        }
+
+        #[test]
+        fn handles_identical_samples() {
+            let histogram = Histogram::from_samples(&[5.0, 5.0]).unwrap();
+            assert_eq!(histogram.buckets[0], 2);
+        }
+
+        #[test]
+        fn handles_no_samples() {
+            assert_eq!(Histogram::from_samples(&[]), None);
+        }
+    }
+
+    mod table_names {
+        use crate::template::timings::{get_file_path, is_valid_table_name};
+        use crate::year;
+
+        #[test]
+        fn resolves_file_paths() {
+            assert_eq!(get_file_path(year!(2024), None), "data/2024/timings.json");
+            assert_eq!(
+                get_file_path(year!(2023), Some("dhat")),
+                "data/2023/timings-dhat.json"
+            );
+        }
+
+        #[test]
+        fn validates_names() {
+            assert!(is_valid_table_name("laptop_2"));
+            assert!(!is_valid_table_name(""));
+            assert!(!is_valid_table_name("a --->"));
+            assert!(!is_valid_table_name("../x"));
+        }
     }
 
     mod is_day_complete {
@@ -285,7 +657,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -299,7 +671,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -313,30 +685,53 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::answers::Answers,
+            template::timings::{MergePolicy, PartTiming, Timing, Timings},
+            template::Part,
         };
 
         use super::get_mock_timings;
 
+        fn merge(timings: &Timings, other: &Timings) -> Timings {
+            timings.merge(other, MergePolicy::Latest, &Answers::default())
+        }
+
+        fn part(time: &str, answer: &str) -> Option<PartTiming> {
+            let mut part = PartTiming::from(time);
+            part.answer = Some(answer.into());
+            Some(part)
+        }
+
+        fn get_new_day_2(part_1: Option<PartTiming>, part_2: Option<PartTiming>) -> Timings {
+            Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    part_1,
+                    part_2,
+                    total_nanos: 0_f64,
+                }],
+            }
+        }
+
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
-                    part_1: None,
+                    part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 0_f64,
                 }],
             };
-            let merged = timings.merge(&other);
+            let merged = merge(&timings, &other);
             assert_eq!(merged.data.len(), 4);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
@@ -356,20 +751,96 @@ mod tests {
                     total_nanos: 0_f64,
                 }],
             };
-            let merged = timings.merge(&other);
+            let merged = merge(&timings, &other);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            // parts that were not benched keep their stored timings.
+            assert_eq!(merged.data[1].part_1.as_ref().unwrap().time, "30ms");
+            assert_eq!(merged.data[1].total_nanos, 70_000_000_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn merges_per_part() {
+            let timings = get_mock_timings();
+            let other = get_new_day_2(None, Some("5ms".into()));
+            let merged = merge(&timings, &other);
+
+            assert_eq!(merged.data[1].part_1.as_ref().unwrap().time, "30ms");
+            assert_eq!(merged.data[1].part_2.as_ref().unwrap().time, "5ms");
+            assert_eq!(merged.data[1].total_nanos, 35_000_000_f64);
+        }
+
+        #[test]
+        fn keeps_best_timings() {
+            let timings = get_mock_timings();
+            let other = get_new_day_2(Some("50ms".into()), Some("5ms".into()));
+            let merged = timings.merge(&other, MergePolicy::Best, &Answers::default());
+
+            assert_eq!(merged.data[1].part_1.as_ref().unwrap().time, "30ms");
+            assert_eq!(merged.data[1].part_2.as_ref().unwrap().time, "5ms");
+        }
+
+        #[test]
+        fn keeps_only_verified_timings() {
+            let timings = get_mock_timings();
+            let mut answers = Answers::default();
+            answers.set(day!(2), Part::One, "42");
+            answers.set(day!(2), Part::Two, "43");
+
+            let other = get_new_day_2(part("1ms", "42"), part("2ms", "0"));
+            let merged = timings.merge(&other, MergePolicy::OnlyIfVerified, &answers);
+            assert_eq!(merged.data[1].part_1.as_ref().unwrap().time, "1ms");
+            assert_eq!(merged.data[1].part_2.as_ref().unwrap().time, "40ms");
+
+            // without a verified answer, nothing is stored.
+            let merged = timings.merge(&other, MergePolicy::OnlyIfVerified, &Answers::default());
+            assert_eq!(merged.data[1].part_1.as_ref().unwrap().time, "30ms");
+        }
+
+        #[test]
+        fn skips_new_days_without_accepted_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(3),
+                    part_1: part("1ms", "42"),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::OnlyIfVerified, &Answers::default());
+            assert_eq!(merged.data.len(), timings.data.len());
+            assert!(merged.data.iter().all(|x| x.day != day!(3)));
+
+            // days without any timings are not added either.
+            let empty = get_new_day_2(None, None);
+            let merged = merge(&Timings::default(), &empty);
+            assert!(merged.data.is_empty());
+        }
+
+        #[test]
+        fn parses_policies() {
+            assert_eq!(
+                "latest".parse::<MergePolicy>().unwrap(),
+                MergePolicy::Latest
+            );
+            assert_eq!("best".parse::<MergePolicy>().unwrap(), MergePolicy::Best);
+            assert_eq!(
+                "only-if-verified".parse::<MergePolicy>().unwrap(),
+                MergePolicy::OnlyIfVerified
+            );
+            assert!("fastest".parse::<MergePolicy>().is_err());
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
             let other = get_mock_timings();
-            let merged = timings.merge(&other);
+            let merged = merge(&timings, &other);
             assert_eq!(merged.data.len(), 3);
         }
 
@@ -377,7 +848,7 @@ mod tests {
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
             let other = Timings::default();
-            let merged = timings.merge(&other);
+            let merged = merge(&timings, &other);
             assert_eq!(merged.data.len(), 3);
         }
     }
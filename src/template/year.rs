@@ -0,0 +1,118 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{config, Day};
+
+/// The year of the first Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A year of advent, starting with the first event in 2015.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year: Year = "2023".parse().unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if there was an event that year,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Returns the default year configured in `AOC_YEAR`, if set.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Returns the default year of commands: `AOC_YEAR` if set, otherwise `year` in `aoc.toml`.
+    pub fn configured() -> Option<Self> {
+        Self::from_env().or(config().year)
+    }
+
+    /// Returns the number of days of this year's event.
+    /// It can be set in the `[days]` table of `aoc.toml`, and defaults to [`Year::default_days`] otherwise.
+    pub fn days(self) -> u8 {
+        config()
+            .days
+            .get(&self)
+            .copied()
+            .unwrap_or_else(|| self.default_days())
+    }
+
+    /// Events up to 2024 have 25 days, later events have 12.
+    pub const fn default_days(self) -> u8 {
+        if self.0 < 2025 {
+            25
+        } else {
+            12
+        }
+    }
+
+    /// Returns `true` if the day is part of this year's event.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.days()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of advent, i.e. {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!("invalid year `", $year, "`, expecting 2015 or later"),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}